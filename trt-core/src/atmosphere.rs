@@ -0,0 +1,149 @@
+use crate::prelude::{Hit, AABB, HitRecord, Ray, Vec3};
+use crate::material::HenyeyGreenstein;
+use crate::texture::Constant;
+use crate::utils::{Rng, thread_rng};
+
+pub struct Atmosphere {
+    density: f32,
+    albedo: Vec3,
+    center: Vec3,
+    radius: f32,
+    phase_function: HenyeyGreenstein<Constant>,
+}
+
+impl Atmosphere {
+    pub fn new(density: f32, albedo: impl Into<Vec3>) -> Self {
+        let albedo = albedo.into();
+
+        Self {
+            density,
+            albedo,
+            center: Vec3::splat(0.),
+            radius: std::f32::INFINITY,
+            phase_function: HenyeyGreenstein::new(Constant::new(albedo), 0.),
+        }
+    }
+
+    pub fn anisotropy(mut self, g: f32) -> Self {
+        self.phase_function = HenyeyGreenstein::new(Constant::new(self.albedo), g);
+        self
+    }
+
+    pub fn bounded(mut self, center: impl Into<Vec3>, radius: f32) -> Self {
+        self.center = center.into();
+        self.radius = radius;
+        self
+    }
+
+    fn extent(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<(f32, f32)> {
+        if self.radius.is_infinite() {
+            return Some((t_min, t_max))
+        }
+
+        let oc = ray.origin - self.center;
+        let a = Vec3::dot(ray.direction, ray.direction);
+        let b = Vec3::dot(oc, ray.direction);
+        let c = Vec3::dot(oc, oc) - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+
+        if discriminant <= 0. {
+            return None
+        }
+
+        let disc_sqrt = discriminant.sqrt();
+        let enter = ((-b - disc_sqrt) / a).max(t_min);
+        let exit = ((-b + disc_sqrt) / a).min(t_max);
+
+        if enter < exit { Some((enter, exit)) } else { None }
+    }
+}
+
+impl Hit for Atmosphere {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
+        if self.density <= 0. {
+            return None
+        }
+
+        let (enter, exit) = self.extent(ray, t_min, t_max)?;
+
+        let hit_distance = -(1. / self.density) * (1. - thread_rng().gen::<f32>()).ln();
+        let t = enter + hit_distance / ray.direction.len();
+
+        if t >= exit {
+            return None
+        }
+
+        let p = ray.point_at_parameter(t);
+        Some(HitRecord {
+            t,
+            p,
+            normal: Vec3::new(1., 0., 0.),
+            front_face: true,
+            tangent: Vec3::new(0., 1., 0.),
+            bitangent: Vec3::new(0., 0., 1.),
+            footprint: 0.,
+            spread: ray.width_at(t),
+            boundary: self as *const Self as usize,
+            object_p: p,
+            object_normal: Vec3::new(1., 0., 0.),
+            mat: &self.phase_function,
+            u: 0.,
+            v: 0.,
+        })
+    }
+
+    fn bounding_box(&self, _t0: f32, _t1: f32) -> Option<AABB> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ray::RayCone;
+
+    fn hit_rate(atmosphere: &Atmosphere, ray: &Ray, t_max: f32) -> f32 {
+        let trials = 20_000;
+        let hits = (0..trials)
+            .filter(|_| atmosphere.hit(ray, 0., t_max).is_some())
+            .count();
+        hits as f32 / trials as f32
+    }
+
+    #[test]
+    fn unbounded_attenuation_follows_distance() {
+        let atmosphere = Atmosphere::new(0.5, (1, 1, 1));
+        let ray = Ray {
+            origin: Vec3::splat(0.),
+            direction: Vec3::new(2., 0., 0.),
+            time: 0.,
+            cone: RayCone::default(),
+        };
+
+        let rate = hit_rate(&atmosphere, &ray, 1.);
+        assert!((rate - (1. - (-1_f32).exp())).abs() < 0.02, "{}", rate);
+    }
+
+    #[test]
+    fn bounded_attenuation_stays_inside_the_sphere() {
+        let atmosphere = Atmosphere::new(0.5, (1, 1, 1)).bounded((0, 0, 0), 1.);
+        let ray = Ray {
+            origin: Vec3::new(-10., 0., 0.),
+            direction: Vec3::new(1., 0., 0.),
+            time: 0.,
+            cone: RayCone::default(),
+        };
+
+        let rate = hit_rate(&atmosphere, &ray, 100.);
+        assert!((rate - (1. - (-1_f32).exp())).abs() < 0.02, "{}", rate);
+
+        for _ in 0..1_000 {
+            if let Some(rec) = atmosphere.hit(&ray, 0., 100.) {
+                assert!(rec.t >= 9. && rec.t <= 11., "{}", rec.t);
+            }
+        }
+
+        let missing = Ray { origin: Vec3::new(-10., 2., 0.), ..ray };
+        assert!(atmosphere.hit(&missing, 0., 100.).is_none());
+    }
+}
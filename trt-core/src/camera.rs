@@ -1,4 +1,5 @@
 use crate::prelude::{Vec3, Ray};
+use crate::ray::RayCone;
 use crate::utils::{random_in_unit_disk, Rng, thread_rng};
 
 pub struct Camera {
@@ -30,9 +31,15 @@ impl Camera {
         Ray {
             origin: self.origin + offset,
             direction,
-            time
+            time,
+            cone: RayCone::default(),
         }
     }
+
+    pub fn pixel_spread(&self, height: usize) -> f32 {
+        let center = self.lower_left_corner + 0.5 * self.horizontal + 0.5 * self.vertical - self.origin;
+        self.vertical.len() / (center.len() * height.max(1) as f32)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,26 @@
+use crate::prelude::Vec3;
+use crate::density::DensityField;
+
+pub struct HeightFalloff {
+    density: f32,
+    falloff: f32,
+    base_height: f32,
+}
+
+impl HeightFalloff {
+    pub fn new(density: f32, falloff: f32, base_height: f32) -> Self {
+        assert!(falloff >= 0., "Height falloff must not be negative");
+        Self { density, falloff, base_height }
+    }
+}
+
+impl DensityField for HeightFalloff {
+    fn density(&self, p: Vec3) -> f32 {
+        let height = (p.y() - self.base_height).max(0.);
+        self.density * (-self.falloff * height).exp()
+    }
+
+    fn max_density(&self) -> f32 {
+        self.density
+    }
+}
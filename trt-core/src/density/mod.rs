@@ -0,0 +1,38 @@
+use crate::prelude::Vec3;
+use std::sync::Arc;
+use std::rc::Rc;
+
+pub trait DensityField {
+    fn density(&self, p: Vec3) -> f32;
+    fn max_density(&self) -> f32;
+}
+
+impl<T: DensityField + ?Sized> DensityField for Arc<T> {
+    fn density(&self, p: Vec3) -> f32 {
+        self.as_ref().density(p)
+    }
+    fn max_density(&self) -> f32 {
+        self.as_ref().max_density()
+    }
+}
+
+impl<T: DensityField + ?Sized> DensityField for Rc<T> {
+    fn density(&self, p: Vec3) -> f32 {
+        self.as_ref().density(p)
+    }
+    fn max_density(&self) -> f32 {
+        self.as_ref().max_density()
+    }
+}
+
+mod noise;
+pub use noise::NoiseDensity;
+
+mod height;
+pub use height::HeightFalloff;
+
+mod voxel;
+pub use voxel::{VoxelGrid, VoxelError};
+
+mod sparse;
+pub use sparse::SparseVoxelGrid;
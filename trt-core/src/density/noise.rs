@@ -0,0 +1,33 @@
+use crate::prelude::Vec3;
+use crate::density::DensityField;
+use crate::perlin::Perlin;
+
+pub struct NoiseDensity {
+    perlin: Perlin,
+    scale: f32,
+    density: f32,
+}
+
+impl NoiseDensity {
+    pub fn new(density: f32, scale: f32) -> Self {
+        Self::seeded(density, scale, 0)
+    }
+
+    pub fn seeded(density: f32, scale: f32, seed: u64) -> Self {
+        Self {
+            perlin: Perlin::seeded(seed),
+            scale,
+            density,
+        }
+    }
+}
+
+impl DensityField for NoiseDensity {
+    fn density(&self, p: Vec3) -> f32 {
+        self.density * self.perlin.turb(self.scale * p, 7).min(1.)
+    }
+
+    fn max_density(&self) -> f32 {
+        self.density
+    }
+}
@@ -0,0 +1,73 @@
+use crate::prelude::{AABB, Vec3};
+use crate::density::{DensityField, VoxelGrid};
+use crate::density::voxel::trilinear;
+
+use std::collections::HashMap;
+
+const BRICK_SIZE: usize = 8;
+
+type Brick = Box<[f32; BRICK_SIZE * BRICK_SIZE * BRICK_SIZE]>;
+
+pub struct SparseVoxelGrid {
+    bounds: AABB,
+    resolution: (usize, usize, usize),
+    bricks: HashMap<(usize, usize, usize), Brick>,
+    max_density: f32,
+}
+
+impl SparseVoxelGrid {
+    pub fn from_dense(grid: &VoxelGrid, threshold: f32) -> Self {
+        let (nx, ny, nz) = grid.resolution();
+        let mut bricks = HashMap::new();
+        let mut max_density = 0f32;
+
+        for z in 0..nz {
+            for y in 0..ny {
+                for x in 0..nx {
+                    let value = grid.voxel(x, y, z);
+                    if value <= threshold {
+                        continue
+                    }
+
+                    max_density = max_density.max(value);
+
+                    let brick = bricks
+                        .entry((x / BRICK_SIZE, y / BRICK_SIZE, z / BRICK_SIZE))
+                        .or_insert_with(|| Box::new([0.; BRICK_SIZE * BRICK_SIZE * BRICK_SIZE]));
+                    brick[brick_index(x, y, z)] = value;
+                }
+            }
+        }
+
+        Self {
+            bounds: grid.bounds().clone(),
+            resolution: grid.resolution(),
+            bricks,
+            max_density,
+        }
+    }
+
+    pub fn active_bricks(&self) -> usize {
+        self.bricks.len()
+    }
+
+    pub fn voxel(&self, x: usize, y: usize, z: usize) -> f32 {
+        self.bricks
+            .get(&(x / BRICK_SIZE, y / BRICK_SIZE, z / BRICK_SIZE))
+            .map_or(0., |brick| brick[brick_index(x, y, z)])
+    }
+}
+
+fn brick_index(x: usize, y: usize, z: usize) -> usize {
+    ((z % BRICK_SIZE) * BRICK_SIZE + y % BRICK_SIZE) * BRICK_SIZE + x % BRICK_SIZE
+}
+
+impl DensityField for SparseVoxelGrid {
+    fn density(&self, p: Vec3) -> f32 {
+        trilinear(&self.bounds, self.resolution, p, |x, y, z| self.voxel(x, y, z))
+    }
+
+    fn max_density(&self) -> f32 {
+        self.max_density
+    }
+}
@@ -0,0 +1,340 @@
+use crate::prelude::{AABB, Vec3};
+use crate::density::DensityField;
+
+use std::{fmt, error::Error, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum VoxelError {
+    Io(io::Error),
+    InvalidHeader,
+    UnsupportedDtype(String),
+    UnsupportedLayout,
+    InvalidShape((usize, usize, usize)),
+    SizeMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for VoxelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoxelError::Io(err) => write!(f, "Failed to read voxel data: {}", err),
+            VoxelError::InvalidHeader => write!(f, "Invalid .npy header"),
+            VoxelError::UnsupportedDtype(dtype) => write!(f, "Unsupported voxel dtype: {}", dtype),
+            VoxelError::UnsupportedLayout => write!(f, "Voxel data must be a 3D C-ordered array"),
+            VoxelError::InvalidShape((nx, ny, nz)) => write!(f, "Invalid voxel grid shape: ({}, {}, {})", nx, ny, nz),
+            VoxelError::SizeMismatch { expected, found } => {
+                write!(f, "Expected {} voxels, found {}", expected, found)
+            },
+        }
+    }
+}
+
+impl Error for VoxelError {}
+
+impl From<io::Error> for VoxelError {
+    fn from(err: io::Error) -> Self {
+        VoxelError::Io(err)
+    }
+}
+
+pub struct VoxelGrid {
+    bounds: AABB,
+    resolution: (usize, usize, usize),
+    data: Vec<f32>,
+    max_density: f32,
+}
+
+impl VoxelGrid {
+    pub fn new(bounds: AABB, resolution: (usize, usize, usize), data: impl Into<Vec<f32>>) -> Self {
+        let data = data.into();
+        let expected = voxel_count(resolution).expect("Invalid voxel grid resolution");
+        assert_eq!(data.len(), expected, "Voxel data and resolution mismatch");
+
+        let max_density = data.iter().cloned().fold(0., f32::max);
+
+        Self { bounds, resolution, data, max_density }
+    }
+
+    pub fn from_raw(bytes: &[u8], resolution: (usize, usize, usize), bounds: AABB) -> Result<Self, VoxelError> {
+        let expected = voxel_count(resolution)?;
+        let data = decode("<f4", bytes)?;
+
+        if data.len() != expected {
+            return Err(VoxelError::SizeMismatch { expected, found: data.len() })
+        }
+
+        Ok(Self::new(bounds, resolution, data))
+    }
+
+    /// Loads a 3D C-ordered `.npy` array indexed as `[x][y][z]`.
+    /// `<f4` and `<f8` values are used as is, `|u1` values are normalised to [0, 1] by dividing by 255.
+    pub fn from_npy(bytes: &[u8], bounds: AABB) -> Result<Self, VoxelError> {
+        let (header, body) = npy_header(bytes)?;
+
+        let dtype = header_value(header, "descr")
+            .map(|descr| descr.trim_matches(|c| c == '\'' || c == '"'))
+            .ok_or(VoxelError::InvalidHeader)?;
+
+        let fortran_order = header_value(header, "fortran_order").ok_or(VoxelError::InvalidHeader)?;
+        if fortran_order != "False" {
+            return Err(VoxelError::UnsupportedLayout)
+        }
+
+        let shape = header_value(header, "shape")
+            .ok_or(VoxelError::InvalidHeader)?
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<usize>().map_err(|_| VoxelError::InvalidHeader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (nx, ny, nz) = match shape[..] {
+            [nx, ny, nz] => (nx, ny, nz),
+            _ => return Err(VoxelError::UnsupportedLayout),
+        };
+
+        let expected = voxel_count((nx, ny, nz))?;
+        let values = decode(dtype, body)?;
+        if values.len() != expected {
+            return Err(VoxelError::SizeMismatch { expected, found: values.len() })
+        }
+
+        let mut data = vec![0.; expected];
+        for x in 0..nx {
+            for y in 0..ny {
+                for z in 0..nz {
+                    data[(z * ny + y) * nx + x] = values[(x * ny + y) * nz + z];
+                }
+            }
+        }
+
+        Ok(Self::new(bounds, (nx, ny, nz), data))
+    }
+
+    pub fn load_npy(path: impl AsRef<Path>, bounds: AABB) -> Result<Self, VoxelError> {
+        Self::from_npy(&fs::read(path)?, bounds)
+    }
+
+    pub fn bounds(&self) -> &AABB {
+        &self.bounds
+    }
+
+    pub fn resolution(&self) -> (usize, usize, usize) {
+        self.resolution
+    }
+
+    pub fn voxel(&self, x: usize, y: usize, z: usize) -> f32 {
+        let (nx, ny, _) = self.resolution;
+        self.data[(z * ny + y) * nx + x]
+    }
+}
+
+fn voxel_count(resolution: (usize, usize, usize)) -> Result<usize, VoxelError> {
+    let (nx, ny, nz) = resolution;
+    nx.checked_mul(ny)
+        .and_then(|n| n.checked_mul(nz))
+        .filter(|&n| n > 0)
+        .ok_or(VoxelError::InvalidShape(resolution))
+}
+
+fn npy_header(bytes: &[u8]) -> Result<(&str, &[u8]), VoxelError> {
+    if bytes.len() < 10 || &bytes[..6] != b"\x93NUMPY" {
+        return Err(VoxelError::InvalidHeader)
+    }
+
+    let (len, start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12),
+        _ => return Err(VoxelError::InvalidHeader),
+    };
+
+    let header = bytes.get(start..start + len).ok_or(VoxelError::InvalidHeader)?;
+    let header = std::str::from_utf8(header).map_err(|_| VoxelError::InvalidHeader)?;
+
+    Ok((header, &bytes[start + len..]))
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let key_start = header.find(&format!("'{}'", key))?;
+    let rest = header[key_start + key.len() + 2..].trim_start();
+    if !rest.starts_with(':') {
+        return None
+    }
+    let rest = rest[1..].trim_start();
+
+    let end = if rest.starts_with('(') {
+        rest.find(')')? + 1
+    } else {
+        rest.find(',').unwrap_or_else(|| rest.len())
+    };
+
+    Some(rest[..end].trim())
+}
+
+fn decode(dtype: &str, bytes: &[u8]) -> Result<Vec<f32>, VoxelError> {
+    let values = match dtype {
+        "<f4" | "=f4" => bytes.chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        "<f8" | "=f8" => bytes.chunks_exact(8)
+            .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32)
+            .collect(),
+        "|u1" | "<u1" => bytes.iter()
+            .map(|&b| b as f32 / 255.)
+            .collect(),
+        _ => return Err(VoxelError::UnsupportedDtype(dtype.to_owned())),
+    };
+
+    Ok(values)
+}
+
+pub(crate) fn trilinear(
+    bounds: &AABB,
+    (nx, ny, nz): (usize, usize, usize),
+    p: Vec3,
+    voxel: impl Fn(usize, usize, usize) -> f32,
+) -> f32 {
+    let extent = bounds.max - bounds.min;
+    let local = (p - bounds.min) / extent;
+
+    let coords = [(local.x(), nx), (local.y(), ny), (local.z(), nz)];
+
+    if coords.iter().any(|&(c, n)| n == 0 || c < 0. || c > 1.) {
+        return 0.
+    }
+
+    let mut cells = [(0, 0, 0.); 3];
+    for (cell, &(c, n)) in cells.iter_mut().zip(&coords) {
+        let g = (c * n as f32 - 0.5).max(0.).min((n - 1) as f32);
+        let i0 = g.floor() as usize;
+        *cell = (i0, (i0 + 1).min(n - 1), g - i0 as f32);
+    }
+
+    let [(x0, x1, fx), (y0, y1, fy), (z0, z1, fz)] = cells;
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+
+    let c00 = lerp(voxel(x0, y0, z0), voxel(x1, y0, z0), fx);
+    let c10 = lerp(voxel(x0, y1, z0), voxel(x1, y1, z0), fx);
+    let c01 = lerp(voxel(x0, y0, z1), voxel(x1, y0, z1), fx);
+    let c11 = lerp(voxel(x0, y1, z1), voxel(x1, y1, z1), fx);
+
+    lerp(lerp(c00, c10, fy), lerp(c01, c11, fy), fz)
+}
+
+impl DensityField for VoxelGrid {
+    fn density(&self, p: Vec3) -> f32 {
+        trilinear(&self.bounds, self.resolution, p, |x, y, z| self.voxel(x, y, z))
+    }
+
+    fn max_density(&self) -> f32 {
+        self.max_density
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> AABB {
+        AABB { min: Vec3::splat(0.), max: Vec3::splat(1.) }
+    }
+
+    fn npy(version: u8, descr: &str, shape: &str, body: &[u8]) -> Vec<u8> {
+        let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+        let prefix = if version == 1 { 10 } else { 12 };
+        while (prefix + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut bytes = b"\x93NUMPY".to_vec();
+        bytes.extend_from_slice(&[version, 0]);
+        match version {
+            1 => bytes.extend_from_slice(&(header.len() as u16).to_le_bytes()),
+            _ => bytes.extend_from_slice(&(header.len() as u32).to_le_bytes()),
+        }
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    #[test]
+    fn header_values() {
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3, 4), }";
+
+        assert_eq!(header_value(header, "descr"), Some("'<f4'"));
+        assert_eq!(header_value(header, "fortran_order"), Some("False"));
+        assert_eq!(header_value(header, "shape"), Some("(2, 3, 4)"));
+        assert_eq!(header_value(header, "missing"), None);
+    }
+
+    #[test]
+    fn npy_header_versions() {
+        for &version in &[1, 2] {
+            let bytes = npy(version, "<f4", "(1, 1, 1)", &1f32.to_le_bytes());
+            let (header, body) = npy_header(&bytes).unwrap();
+
+            assert!(header.starts_with("{'descr'"));
+            assert_eq!(body, &1f32.to_le_bytes());
+        }
+
+        assert!(npy_header(b"not a numpy file").is_err());
+    }
+
+    #[test]
+    fn f4_c_order_is_transposed() {
+        let body: Vec<u8> = (0..24).flat_map(|i| (i as f32).to_le_bytes().to_vec()).collect();
+        let grid = VoxelGrid::from_npy(&npy(1, "<f4", "(2, 3, 4)", &body), bounds()).unwrap();
+
+        assert_eq!(grid.resolution(), (2, 3, 4));
+        for x in 0..2 {
+            for y in 0..3 {
+                for z in 0..4 {
+                    assert_eq!(grid.voxel(x, y, z) as usize, (x * 3 + y) * 4 + z);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn f8_v2_header() {
+        let body: Vec<u8> = (0..8).flat_map(|i| (i as f64 * 0.5).to_le_bytes().to_vec()).collect();
+        let grid = VoxelGrid::from_npy(&npy(2, "<f8", "(2, 2, 2)", &body), bounds()).unwrap();
+
+        assert!((grid.voxel(1, 1, 1) - 3.5).abs() < 1e-6);
+        assert!((grid.voxel(1, 0, 0) - 2.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn u1_is_normalised() {
+        let grid = VoxelGrid::from_npy(&npy(1, "|u1", "(1, 1, 2)", &[0, 255]), bounds()).unwrap();
+
+        assert!(grid.voxel(0, 0, 0).abs() < 1e-6);
+        assert!((grid.voxel(0, 0, 1) - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn invalid_shapes_are_rejected() {
+        let zero = VoxelGrid::from_npy(&npy(1, "<f4", "(0, 4, 4)", &[]), bounds());
+        assert!(matches!(zero, Err(VoxelError::InvalidShape(_))));
+
+        let huge = format!("({}, 4, 4)", std::usize::MAX);
+        let overflow = VoxelGrid::from_npy(&npy(1, "<f4", &huge, &[]), bounds());
+        assert!(matches!(overflow, Err(VoxelError::InvalidShape(_))));
+
+        assert!(matches!(VoxelGrid::from_raw(&[], (4, 0, 4), bounds()), Err(VoxelError::InvalidShape(_))));
+    }
+
+    #[test]
+    fn unsupported_data_is_rejected() {
+        let body = 1f32.to_be_bytes();
+        assert!(matches!(
+            VoxelGrid::from_npy(&npy(1, ">f4", "(1, 1, 1)", &body), bounds()),
+            Err(VoxelError::UnsupportedDtype(_))
+        ));
+        assert!(matches!(
+            VoxelGrid::from_npy(&npy(1, "<f4", "(1, 1, 1)", &[0; 8]), bounds()),
+            Err(VoxelError::SizeMismatch { expected: 1, found: 2 })
+        ));
+    }
+}
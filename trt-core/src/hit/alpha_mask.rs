@@ -0,0 +1,101 @@
+use crate::prelude::{Texture, Hit, AABB, HitRecord, Ray};
+
+const MAX_LAYERS: usize = 64;
+
+pub struct AlphaMask<T: Hit, Tx: Texture> {
+    wrapped: T,
+    opacity: Tx,
+    threshold: f32,
+}
+
+impl<T: Hit, Tx: Texture> AlphaMask<T, Tx> {
+    pub fn new(wrapped: T, opacity: Tx, threshold: f32) -> Self {
+        Self { wrapped, opacity, threshold }
+    }
+}
+
+impl<T: Hit, Tx: Texture> Hit for AlphaMask<T, Tx> {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
+        let mut t_min = t_min;
+
+        for _ in 0..MAX_LAYERS {
+            let rec = self.wrapped.hit(ray, t_min, t_max)?;
+
+            if self.opacity.value_at(&rec).x() >= self.threshold {
+                return Some(rec)
+            }
+
+            t_min = (rec.t + 0.0001).max(next_up(rec.t));
+        }
+
+        None
+    }
+
+    fn bounding_box(&self, t0: f32, t1: f32) -> Option<AABB> {
+        self.wrapped.bounding_box(t0, t1)
+    }
+}
+
+fn next_up(t: f32) -> f32 {
+    match t {
+        t if t.is_nan() || t == std::f32::INFINITY => t,
+        t if t == 0. => std::f32::MIN_POSITIVE,
+        t if t > 0. => f32::from_bits(t.to_bits() + 1),
+        t => f32::from_bits(t.to_bits() - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hit::RectBuilder;
+    use crate::material::{Lambertian, MaterialBuilder};
+    use crate::prelude::Vec3;
+    use crate::ray::RayCone;
+    use crate::texture::{AlphaChannel, Constant, FilterMode, Image};
+
+    #[test]
+    fn transparent_rect_far_along_ray() {
+        let rect = RectBuilder.x(-1..=1).y(-1..=1).z(0)
+            .material(Lambertian::new(Constant::splat(0.5)))
+            .alpha_mask(Constant::splat(0.), 0.5);
+
+        let ray = Ray {
+            origin: Vec3::new(0, 0, 10),
+            direction: Vec3::new(0, 0, -0.001),
+            time: 0.,
+            cone: RayCone::default(),
+        };
+
+        assert!(rect.hit(&ray, 0.001, std::f32::MAX).is_none());
+    }
+
+    #[test]
+    fn next_up_is_strictly_greater() {
+        for &t in &[0., 1e-3, 1., 2048., 1e4, 1e30, -1., -1e4] {
+            assert!(next_up(t) > t);
+        }
+    }
+
+    #[test]
+    fn mipmapped_cutout_survives_the_ray_footprint() {
+        let stripes: Vec<f32> = (0..64 * 64)
+            .flat_map(|i| vec![1., if (i % 64) / 8 % 2 == 0 { 1. } else { 0. }])
+            .collect();
+        let alpha = AlphaChannel::new(Image::from_f32(stripes, 64, 64, 2).filter(FilterMode::Trilinear));
+
+        let rect = RectBuilder.x(-1..=1).y(-1..=1).z(0)
+            .material(Lambertian::new(Constant::splat(0.5)))
+            .alpha_mask(alpha, 0.75);
+
+        let ray = |x: f32| Ray {
+            origin: Vec3::new(x, 0, 10),
+            direction: Vec3::new(0, 0, -1),
+            time: 0.,
+            cone: RayCone { width: 0., spread: 0.001 },
+        };
+
+        assert!(rect.hit(&ray(-0.875), 0.001, std::f32::MAX).is_some());
+        assert!(rect.hit(&ray(-0.625), 0.001, std::f32::MAX).is_none());
+    }
+}
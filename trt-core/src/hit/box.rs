@@ -1,6 +1,7 @@
 use crate::prelude::{Hit, Material, MaterialBuilder, HitRecord, AABB, Ray, Vec3};
 use crate::hit::RectBuilder;
 
+/// Each face is a `Rect` whose UVs read unmirrored when seen from outside the box.
 pub struct HitBox<T> {
     pmin: Vec3,
     pmax: Vec3,
@@ -24,20 +25,21 @@ impl<T: Material + Clone> HitBox<T> {
 
         world![
             RectBuilder.x(x0..=x1).y(y0..=y1).z(z1).material(mat.clone()),
-            RectBuilder.x(x0..=x1).y(y0..=y1).z(z0).material(mat.clone()).flip_normals(),
+            RectBuilder.x(x0..=x1).y(y0..=y1).z(z0).material(mat.clone()).mirror_u().flip_normals(),
 
-            RectBuilder.x(x0..=x1).z(z0..=z1).y(y1).material(mat.clone()),
+            RectBuilder.x(x0..=x1).z(z0..=z1).y(y1).material(mat.clone()).mirror_u(),
             RectBuilder.x(x0..=x1).z(z0..=z1).y(y0).material(mat.clone()).flip_normals(),
 
             RectBuilder.y(y0..=y1).z(z0..=z1).x(x1).material(mat.clone()),
-            RectBuilder.y(y0..=y1).z(z0..=z1).x(x0).material(mat.clone()).flip_normals(),
+            RectBuilder.y(y0..=y1).z(z0..=z1).x(x0).material(mat.clone()).mirror_u().flip_normals(),
         ]
     }
 }
 
 impl<T: Material> Hit for HitBox<T> {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
-        self.list.hit(ray, t_min, t_max)
+        let rec = self.list.hit(ray, t_min, t_max)?;
+        Some(HitRecord { boundary: self as *const Self as usize, ..rec })
     }
 
     fn bounding_box(&self, _t0: f32, _t1: f32) -> Option<AABB> {
@@ -1,6 +1,7 @@
 use crate::prelude::{Material, Texture, Hit, AABB, HitRecord, Ray, Vec3};
-use crate::material::Isotropic;
+use crate::material::{Isotropic, HenyeyGreenstein};
 
+/// Scattering events take the UVs of the point where the ray entered the boundary.
 pub struct ConstantMedium<T: Hit, Mat: Material> {
     boundary: T,
     density: f32,
@@ -19,6 +20,12 @@ impl<T: Hit, Tx: Texture> ConstantMedium<T, Isotropic<Tx>> {
     }
 }
 
+impl<T: Hit, Tx: Texture> ConstantMedium<T, HenyeyGreenstein<Tx>> {
+    pub fn new_hg(boundary: T, density: f32, texture: Tx, g: f32) -> Self {
+        Self::new(boundary, density, HenyeyGreenstein::new(texture, g))
+    }
+}
+
 impl<T: Hit, Mat: Material> Hit for ConstantMedium<T, Mat> {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
         let f_max = std::f32::MAX;
@@ -44,13 +51,22 @@ impl<T: Hit, Mat: Material> Hit for ConstantMedium<T, Mat> {
 
         let t = rec1.t + hit_distance / ray.direction.len();
 
+        let p = ray.point_at_parameter(t);
         Some(HitRecord {
             t,
-            p: ray.point_at_parameter(t),
+            p,
             normal: Vec3::new(1., 0., 0.),
+            front_face: true,
+            tangent: Vec3::new(0., 1., 0.),
+            bitangent: Vec3::new(0., 0., 1.),
+            footprint: 0.,
+            spread: ray.width_at(t),
+            boundary: self as *const Self as usize,
+            object_p: p,
+            object_normal: Vec3::new(1., 0., 0.),
             mat: &self.phase_function,
-            u: 0.,
-            v: 0.,
+            u: rec1.u,
+            v: rec1.v,
         })
     }
 
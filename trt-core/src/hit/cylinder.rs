@@ -1,6 +1,8 @@
 use crate::prelude::{Material, Hit, AABB, HitRecord, Ray, Vec3, Asf32};
-use crate::{utils::cylinder_uv, material::MaterialBuilder};
+use crate::{utils::{cylinder_uv, disk_uv, tangent_frame, face_normal}, material::MaterialBuilder};
+use std::f32::consts::PI;
 
+/// Side UVs follow `cylinder_uv` from the base, caps follow `disk_uv` from the cap centers.
 pub struct Cylinder<Mat> {
     base: Vec3,
     height: f32,
@@ -62,17 +64,37 @@ impl<Mat: Material> Hit for Cylinder<Mat> {
             if far > near && near < t_max && near > t_min {
                 let t = near;
                 let p = ray.point_at_parameter(t);
-                let normal = if capped {
-                    Vec3::new(0, if cap_neg { -1. } else { 1. }, 0)
+                let spread = ray.width_at(t);
+                let centered = p - self.base;
+                let (outward_normal, dpdu, (u, v), footprint) = if capped {
+                    (
+                        Vec3::new(0, if cap_neg { -1. } else { 1. }, 0),
+                        Vec3::new(1., 0., 0.),
+                        disk_uv(centered, self.radius),
+                        spread / (2. * self.radius),
+                    )
                 } else {
-                    let centered = p - self.base;
-                    Vec3::new(centered.x(), 0., centered.z()) / self.radius
+                    (
+                        Vec3::new(centered.x(), 0., centered.z()) / self.radius,
+                        Vec3::new(centered.z(), 0., -centered.x()),
+                        cylinder_uv(centered, self.height),
+                        spread / (2. * PI * self.radius).min(self.height),
+                    )
                 };
-                let (u, v) = cylinder_uv(p);
+                let (tangent, bitangent) = tangent_frame(outward_normal, dpdu);
+                let (front_face, normal) = face_normal(ray.direction, outward_normal);
                 return Some(HitRecord {
                     t,
                     p,
                     normal,
+                    front_face,
+                    tangent,
+                    bitangent,
+                    footprint,
+                    spread,
+                    boundary: self as *const Self as usize,
+                    object_p: centered,
+                    object_normal: outward_normal,
                     mat: &self.material,
                     u, v
                 })
@@ -13,7 +13,7 @@ impl<T: Hit> FlipNormals<T> {
 impl<T: Hit> Hit for FlipNormals<T> {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
         let mut rec = self.wrapped.hit(ray, t_min, t_max)?;
-        rec.normal = -rec.normal;
+        rec.front_face = !rec.front_face;
         Some(rec)
     }
 
@@ -0,0 +1,88 @@
+use crate::prelude::{Material, Texture, Hit, AABB, HitRecord, Ray, Vec3};
+use crate::density::DensityField;
+use crate::material::{Isotropic, HenyeyGreenstein};
+use crate::utils::{Rng, thread_rng};
+
+/// Scattering events take the UVs of the point where the ray entered the boundary.
+pub struct HeterogeneousMedium<T: Hit, D: DensityField, Mat: Material> {
+    boundary: T,
+    density: D,
+    phase_function: Mat,
+}
+
+impl<T: Hit, D: DensityField, Mat: Material> HeterogeneousMedium<T, D, Mat> {
+    pub fn new(boundary: T, density: D, phase_function: Mat) -> Self {
+        Self { boundary, density, phase_function }
+    }
+}
+
+impl<T: Hit, D: DensityField, Tx: Texture> HeterogeneousMedium<T, D, Isotropic<Tx>> {
+    pub fn new_iso(boundary: T, density: D, texture: Tx) -> Self {
+        Self::new(boundary, density, Isotropic::new(texture))
+    }
+}
+
+impl<T: Hit, D: DensityField, Tx: Texture> HeterogeneousMedium<T, D, HenyeyGreenstein<Tx>> {
+    pub fn new_hg(boundary: T, density: D, texture: Tx, g: f32) -> Self {
+        Self::new(boundary, density, HenyeyGreenstein::new(texture, g))
+    }
+}
+
+impl<T: Hit, D: DensityField, Mat: Material> Hit for HeterogeneousMedium<T, D, Mat> {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
+        let f_max = std::f32::MAX;
+
+        let mut rec1 = self.boundary.hit(ray, -f_max, f_max)?;
+        let mut rec2 = self.boundary.hit(ray, rec1.t + 0.0001, f_max)?;
+
+        if rec1.t < t_min { rec1.t = t_min }
+        if rec2.t > t_max { rec2.t = t_max }
+
+        if rec1.t >= rec2.t {
+            return None
+        }
+
+        if rec1.t < 0. { rec1.t = 0. }
+
+        let majorant = self.density.max_density();
+        if majorant <= 0. {
+            return None
+        }
+
+        let mut rng = thread_rng();
+        let ray_length = ray.direction.len();
+        let mut t = rec1.t;
+
+        loop {
+            t -= (1. - rng.gen::<f32>()).ln() / (majorant * ray_length);
+
+            if t >= rec2.t {
+                return None
+            }
+
+            let p = ray.point_at_parameter(t);
+            if rng.gen::<f32>() * majorant < self.density.density(p) {
+                return Some(HitRecord {
+                    t,
+                    p,
+                    normal: Vec3::new(1., 0., 0.),
+                    front_face: true,
+                    tangent: Vec3::new(0., 1., 0.),
+                    bitangent: Vec3::new(0., 0., 1.),
+                    footprint: 0.,
+                    spread: ray.width_at(t),
+                    boundary: self as *const Self as usize,
+                    object_p: p,
+                    object_normal: Vec3::new(1., 0., 0.),
+                    mat: &self.phase_function,
+                    u: rec1.u,
+                    v: rec1.v,
+                })
+            }
+        }
+    }
+
+    fn bounding_box(&self, t0: f32, t1: f32) -> Option<AABB> {
+        self.boundary.bounding_box(t0, t1)
+    }
+}
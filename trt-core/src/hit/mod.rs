@@ -1,6 +1,8 @@
-use crate::prelude::{Material, AABB, Ray, Vec3};
-use crate::material::Isotropic;
+use crate::prelude::{Material, Texture, AABB, Ray, Vec3};
+use crate::material::{Isotropic, HenyeyGreenstein, Emissive};
 use crate::texture::Constant;
+use crate::density::DensityField;
+use crate::utils::tangent_frame;
 
 use std::{sync::Arc, rc::Rc};
 
@@ -10,9 +12,31 @@ pub struct HitRecord<'mat> {
     pub v: f32,
     pub p: Vec3,
     pub normal: Vec3,
+    pub front_face: bool,
+    pub tangent: Vec3,
+    pub bitangent: Vec3,
+    pub footprint: f32,
+    pub spread: f32,
+    pub boundary: usize,
+    pub object_p: Vec3,
+    pub object_normal: Vec3,
     pub mat: &'mat dyn Material,
 }
 
+impl<'mat> HitRecord<'mat> {
+    pub fn shading(&self, normal: Vec3) -> Self {
+        let normal = normal.unit();
+        let (tangent, bitangent) = tangent_frame(normal, self.tangent);
+
+        Self {
+            normal,
+            tangent,
+            bitangent: if Vec3::dot(bitangent, self.bitangent) < 0. { -bitangent } else { bitangent },
+            ..*self
+        }
+    }
+}
+
 pub trait Hit {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>>;
     fn bounding_box(&self, t0: f32, t1: f32) -> Option<AABB>;
@@ -59,6 +83,13 @@ pub trait Hit {
         RotateZ::new(self, angle)
     }
 
+    fn alpha_mask<Tx: Texture>(self, opacity: Tx, threshold: f32) -> AlphaMask<Self, Tx>
+    where
+        Self: Sized
+    {
+        AlphaMask::new(self, opacity, threshold)
+    }
+
     fn constant_medium(self, density: f32, color: impl Into<Vec3>)
         -> ConstantMedium<Self, Isotropic<Constant>>
     where
@@ -66,6 +97,46 @@ pub trait Hit {
     {
         ConstantMedium::new_iso(self, density, Constant::new(color.into()))
     }
+
+    fn constant_medium_hg(self, density: f32, color: impl Into<Vec3>, g: f32)
+        -> ConstantMedium<Self, HenyeyGreenstein<Constant>>
+    where
+        Self: Sized
+    {
+        ConstantMedium::new_hg(self, density, Constant::new(color.into()), g)
+    }
+
+    fn emissive_medium<Tx: Texture>(self, density: f32, color: impl Into<Vec3>, emission: Tx, g: f32)
+        -> ConstantMedium<Self, Emissive<HenyeyGreenstein<Constant>, Tx>>
+    where
+        Self: Sized
+    {
+        let phase_function = HenyeyGreenstein::new(Constant::new(color.into()), g);
+        ConstantMedium::new(self, density, Emissive::new(phase_function, emission))
+    }
+
+    fn heterogeneous_medium<D: DensityField>(self, density: D, color: impl Into<Vec3>)
+        -> HeterogeneousMedium<Self, D, Isotropic<Constant>>
+    where
+        Self: Sized
+    {
+        HeterogeneousMedium::new_iso(self, density, Constant::new(color.into()))
+    }
+
+    fn heterogeneous_medium_hg<D: DensityField>(self, density: D, color: impl Into<Vec3>, g: f32)
+        -> HeterogeneousMedium<Self, D, HenyeyGreenstein<Constant>>
+    where
+        Self: Sized
+    {
+        HeterogeneousMedium::new_hg(self, density, Constant::new(color.into()), g)
+    }
+
+    fn subsurface(self, ref_idx: f32, scattering: impl Into<Vec3>, absorption: impl Into<Vec3>) -> Subsurface<Self>
+    where
+        Self: Sized
+    {
+        Subsurface::new(self, ref_idx, scattering.into(), absorption.into())
+    }
 }
 
 impl<T: Hit + ?Sized> Hit for Box<T> {
@@ -136,5 +207,165 @@ pub use translate::Translate;
 mod rotate;
 pub use rotate::{RotateY, RotateX, RotateZ};
 
+mod alpha_mask;
+pub use alpha_mask::AlphaMask;
+
 mod constant_medium;
 pub use constant_medium::ConstantMedium;
+
+mod heterogeneous_medium;
+pub use heterogeneous_medium::HeterogeneousMedium;
+
+mod subsurface;
+pub use subsurface::Subsurface;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::density::HeightFalloff;
+    use crate::material::{Lambertian, MaterialBuilder};
+    use crate::utils::random_in_unit_sphere;
+    use crate::ray::RayCone;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn material() -> Rc<Lambertian<Constant>> {
+        Rc::new(Lambertian::new(Constant::splat(0.5)))
+    }
+
+    fn random_ray(rng: &mut StdRng) -> Ray {
+        let origin = 10. * random_in_unit_sphere(&mut *rng).unit();
+        let target = 1.5 * random_in_unit_sphere(&mut *rng);
+
+        Ray {
+            origin,
+            direction: target - origin,
+            time: rng.gen(),
+            cone: RayCone::default(),
+        }
+    }
+
+    fn assert_uvs_in_range(hit: &impl Hit) {
+        let mut rng = StdRng::seed_from_u64(0xDEAD_BEEF);
+        let mut hits = 0;
+
+        for _ in 0..10_000 {
+            let ray = random_ray(&mut rng);
+            if let Some(rec) = hit.hit(&ray, 0.001, std::f32::MAX) {
+                hits += 1;
+                assert!((0. ..=1.).contains(&rec.u), "u out of range: {} at {:?}", rec.u, rec.p);
+                assert!((0. ..=1.).contains(&rec.v), "v out of range: {} at {:?}", rec.v, rec.p);
+            }
+        }
+
+        assert!(hits > 1_000, "too few hits to check UVs: {}", hits);
+    }
+
+    fn hit_towards(hit: &impl Hit, origin: Vec3, target: Vec3) -> HitRecord<'_> {
+        let ray = Ray { origin, direction: target - origin, time: 0., cone: RayCone::default() };
+        hit.hit(&ray, 0.001, std::f32::MAX).expect("ray should hit")
+    }
+
+    #[test]
+    fn object_space_is_center_relative() {
+        use crate::texture::{Triplanar, TriplanarSpace, UvChecker};
+
+        let texture = Triplanar::new(UvChecker::new(Constant::splat(0.), Constant::splat(1.), 4., 4.), 1.)
+            .space(TriplanarSpace::Object);
+
+        let at_origin = Sphere::builder().center((0, 0, 0)).radius(1.).material(material());
+        let centered = Sphere::builder().center((5, 2, 0)).radius(1.).material(material());
+        let translated = Sphere::builder().center((0, 0, 0)).radius(1.).material(material()).translate((5, 2, 0));
+        let moving = MovingSphere::builder()
+            .center_from((5, 2, 0))
+            .center_to((5, 2, 0))
+            .radius(1.)
+            .material(material());
+
+        let offset = Vec3::new(5, 2, 0);
+        let (from, target) = (Vec3::new(3, 10, 4), Vec3::new(0.2, 0.3, -0.1));
+        let reference = hit_towards(&at_origin, from, target);
+
+        let records = [
+            hit_towards(&centered, from + offset, target + offset),
+            hit_towards(&translated, from + offset, target + offset),
+            hit_towards(&moving, from + offset, target + offset),
+        ];
+        for rec in &records {
+            assert!((rec.object_p - reference.object_p).len() < 1e-4, "{:?} vs {:?}", rec.object_p, reference.object_p);
+            assert!((texture.value_at(rec) - texture.value_at(&reference)).len() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn sphere_uvs() {
+        let sphere = Sphere::builder().center((0, 0, 0)).radius(1.).material(material());
+        assert_uvs_in_range(&sphere);
+
+        let top = hit_towards(&sphere, Vec3::new(0, 10, 0), Vec3::splat(0.));
+        assert!(top.v > 0.99);
+    }
+
+    #[test]
+    fn moving_sphere_uvs() {
+        let sphere = MovingSphere::builder()
+            .center_from((0, -0.2, 0))
+            .center_to((0, 0.2, 0))
+            .radius(1.)
+            .material(material());
+        assert_uvs_in_range(&sphere);
+
+        let bottom = hit_towards(&sphere, Vec3::new(0, -10, 0), Vec3::new(0, -0.2, 0));
+        assert!(bottom.v < 0.01);
+    }
+
+    #[test]
+    fn cylinder_uvs() {
+        let cylinder = Cylinder::builder().base((0, -1, 0)).height(2.).radius(1.).material(material());
+        assert_uvs_in_range(&cylinder);
+
+        let moved = Cylinder::builder().base((5, 10, 0)).height(2.).radius(1.).material(material());
+        let side = hit_towards(&moved, Vec3::new(15, 11, 0), Vec3::new(5, 11, 0));
+        assert!((side.v - 0.5).abs() < 0.01);
+
+        let cap = hit_towards(&moved, Vec3::new(5.1, 20, 0), Vec3::new(5, 10, 0));
+        assert!((cap.u - 0.51).abs() < 0.01 && (cap.v - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn rect_uvs() {
+        assert_uvs_in_range(&RectBuilder.x(-1..=1).y(-1..=1).z(0).material(material()));
+        assert_uvs_in_range(&RectBuilder.x(-1..=1).z(-1..=1).y(0).material(material()));
+        assert_uvs_in_range(&RectBuilder.y(-1..=1).z(-1..=1).x(0).material(material()));
+    }
+
+    #[test]
+    fn hitbox_uvs() {
+        let hitbox = HitBox::new(Vec3::splat(-1.), Vec3::splat(1.), material());
+        assert_uvs_in_range(&hitbox);
+        assert_uvs_in_range(&HitBox::new(Vec3::splat(-1.), Vec3::splat(1.), material()).rotate_y(30.));
+
+        let faces = [
+            Vec3::new(1, 0, 0), Vec3::new(-1, 0, 0),
+            Vec3::new(0, 1, 0), Vec3::new(0, -1, 0),
+            Vec3::new(0, 0, 1), Vec3::new(0, 0, -1),
+        ];
+
+        for &outward in &faces {
+            let rec = hit_towards(&hitbox, 10. * outward, Vec3::splat(0.));
+            let handedness = Vec3::dot(Vec3::cross(rec.tangent, rec.bitangent), rec.normal);
+            assert!(handedness > 0., "mirrored UVs on face {:?}", outward);
+
+            let shifted = hit_towards(&hitbox, 10. * outward + 0.1 * rec.tangent, 0.1 * rec.tangent);
+            assert!(shifted.u > rec.u, "u decreases along the tangent on face {:?}", outward);
+        }
+    }
+
+    #[test]
+    fn medium_uvs() {
+        let boundary = || Sphere::builder().center((0, 0, 0)).radius(1.).material(material());
+
+        assert_uvs_in_range(&boundary().constant_medium(10., (1, 1, 1)));
+        assert_uvs_in_range(&boundary().heterogeneous_medium_hg(HeightFalloff::new(10., 0., 0.), (1, 1, 1), 0.5));
+    }
+}
@@ -1,17 +1,28 @@
 use crate::prelude::{Material, Hit, AABB, HitRecord, Ray, Vec3, Dimension, X, Y, Z, Asf32};
 use crate::material::MaterialBuilder;
+use crate::utils::face_normal;
 use std::{ops::RangeInclusive, marker::PhantomData};
 
 type DimRange = RangeInclusive<f32>;
 
+/// `u` spans the first range and `v` the second, both over [0, 1].
+/// `mirror_u` reverses `u` so the face reads correctly from its other side.
 pub struct Rect<D1, D2, D3, Mat> {
     d1_range: DimRange,
     d2_range: DimRange,
     d3: f32,
+    mirrored: bool,
     material: Mat,
     tag: PhantomData<(D1, D2, D3)>,
 }
 
+impl<D1, D2, D3, Mat> Rect<D1, D2, D3, Mat> {
+    pub fn mirror_u(mut self) -> Self {
+        self.mirrored = !self.mirrored;
+        self
+    }
+}
+
 impl<D1, D2, D3, Mat> Hit for Rect<D1, D2, D3, Mat>
 where
     D1: Dimension,
@@ -37,13 +48,27 @@ where
         let (d2_0, d2_1) = (self.d2_range.start(), self.d2_range.end());
 
         let u = (d1 - d1_0) / (d1_1 - d1_0);
+        let (u, tangent) = if self.mirrored { (1. - u, -1.) } else { (u, 1.) };
         let v = (d2 - d2_0) / (d2_1 - d2_0);
 
+        let p = ray.point_at_parameter(t);
+        let spread = ray.width_at(t);
+        let outward_normal = Vec3::splat(0.).set::<D3>(1.);
+        let (front_face, normal) = face_normal(ray.direction, outward_normal);
+
         Some(HitRecord {
             u, v, t,
             mat: &self.material,
-            p: ray.point_at_parameter(t),
-            normal: Vec3::splat(0.).set::<D3>(1.),
+            p,
+            normal,
+            front_face,
+            tangent: Vec3::splat(0.).set::<D1>(tangent),
+            bitangent: Vec3::splat(0.).set::<D2>(1.),
+            footprint: spread / (d1_1 - d1_0).max(d2_1 - d2_0),
+            spread,
+            boundary: self as *const Self as usize,
+            object_p: p,
+            object_normal: outward_normal,
         })
     }
 
@@ -155,6 +180,7 @@ impl<D1, D2, D3, Mat> MaterialBuilder<Mat> for ThreeBoundedRectBuilder<D1, D2, D
             d1_range: self.d1_range,
             d2_range: self.d2_range,
             d3: self.d3,
+            mirrored: false,
             material,
             tag: PhantomData,
         }
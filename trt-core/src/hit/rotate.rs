@@ -38,6 +38,7 @@ impl<T: Hit> Hit for RotateY<T> {
             origin,
             direction,
             time: ray.time,
+            cone: ray.cone,
         };
 
         let mut rec = self.hittable.hit(&rotated_ray, t_min, t_max)?;
@@ -46,12 +47,14 @@ impl<T: Hit> Hit for RotateY<T> {
             .set::<X>(self.cos_theta * rec.p.x() + self.sin_theta * rec.p.z())
             .set::<Z>(-self.sin_theta * rec.p.x() + self.cos_theta * rec.p.z());
 
-        let normal = rec.normal
-            .set::<X>(self.cos_theta * rec.normal.x() + self.sin_theta * rec.normal.z())
-            .set::<Z>(-self.sin_theta * rec.normal.x() + self.cos_theta * rec.normal.z());
+        let rotate = |v: Vec3| v
+            .set::<X>(self.cos_theta * v.x() + self.sin_theta * v.z())
+            .set::<Z>(-self.sin_theta * v.x() + self.cos_theta * v.z());
 
         rec.p = p;
-        rec.normal = normal;
+        rec.normal = rotate(rec.normal);
+        rec.tangent = rotate(rec.tangent);
+        rec.bitangent = rotate(rec.bitangent);
 
         Some(rec)
     }
@@ -99,6 +102,7 @@ impl<T: Hit> Hit for RotateX<T> {
             origin,
             direction,
             time: ray.time,
+            cone: ray.cone,
         };
 
         let mut rec = self.hittable.hit(&rotated_ray, t_min, t_max)?;
@@ -107,12 +111,14 @@ impl<T: Hit> Hit for RotateX<T> {
             .set::<Y>(self.cos_theta * rec.p.y() - self.sin_theta * rec.p.z())
             .set::<Z>(self.sin_theta * rec.p.y() + self.cos_theta * rec.p.z());
 
-        let normal = rec.normal
-            .set::<Y>(self.cos_theta * rec.normal.y() - self.sin_theta * rec.normal.z())
-            .set::<Z>(self.sin_theta * rec.normal.y() + self.cos_theta * rec.normal.z());
+        let rotate = |v: Vec3| v
+            .set::<Y>(self.cos_theta * v.y() - self.sin_theta * v.z())
+            .set::<Z>(self.sin_theta * v.y() + self.cos_theta * v.z());
 
         rec.p = p;
-        rec.normal = normal;
+        rec.normal = rotate(rec.normal);
+        rec.tangent = rotate(rec.tangent);
+        rec.bitangent = rotate(rec.bitangent);
 
         Some(rec)
     }
@@ -160,6 +166,7 @@ impl<T: Hit> Hit for RotateZ<T> {
             origin,
             direction,
             time: ray.time,
+            cone: ray.cone,
         };
 
         let mut rec = self.hittable.hit(&rotated_ray, t_min, t_max)?;
@@ -168,12 +175,14 @@ impl<T: Hit> Hit for RotateZ<T> {
             .set::<X>(self.cos_theta * rec.p.x() - self.sin_theta * rec.p.y())
             .set::<Y>(self.sin_theta * rec.p.x() + self.cos_theta * rec.p.y());
 
-        let normal = rec.normal
-            .set::<X>(self.cos_theta * rec.normal.x() - self.sin_theta * rec.normal.y())
-            .set::<Y>(self.sin_theta * rec.normal.x() + self.cos_theta * rec.normal.y());
+        let rotate = |v: Vec3| v
+            .set::<X>(self.cos_theta * v.x() - self.sin_theta * v.y())
+            .set::<Y>(self.sin_theta * v.x() + self.cos_theta * v.y());
 
         rec.p = p;
-        rec.normal = normal;
+        rec.normal = rotate(rec.normal);
+        rec.tangent = rotate(rec.tangent);
+        rec.bitangent = rotate(rec.bitangent);
 
         Some(rec)
     }
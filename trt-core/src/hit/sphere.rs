@@ -1,7 +1,9 @@
 use crate::prelude::{Material, Hit, AABB, HitRecord, Ray, Vec3, Asf32};
 use crate::material::MaterialBuilder;
-use crate::utils::sphere_uv;
+use crate::utils::{sphere_uv, tangent_frame, face_normal};
+use std::f32::consts::PI;
 
+/// UVs follow `sphere_uv` around the center.
 pub struct Sphere<Mat> {
     center: Vec3,
     radius: f32,
@@ -29,9 +31,26 @@ impl<Mat: Material> Hit for Sphere<Mat> {
             for &solution in &[(-b - disc_sqrt) / a, (-b + disc_sqrt) / a] {
                 if solution < t_max && solution > t_min {
                     let p = ray.point_at_parameter(solution);
-                    let normal = (p - self.center) / self.radius;
-                    let (u, v) = sphere_uv((p - self.center) / self.radius);
-                    return Some(HitRecord { t: solution, p, normal, mat: &self.material, u, v })
+                    let spread = ray.width_at(solution);
+                    let outward_normal = (p - self.center) / self.radius;
+                    let (u, v) = sphere_uv(outward_normal);
+                    let (tangent, bitangent) = tangent_frame(outward_normal, Vec3::new(outward_normal.z(), 0., -outward_normal.x()));
+                    let (front_face, normal) = face_normal(ray.direction, outward_normal);
+                    return Some(HitRecord {
+                        t: solution,
+                        p,
+                        normal,
+                        front_face,
+                        tangent,
+                        bitangent,
+                        footprint: spread / (PI * self.radius),
+                        spread,
+                        boundary: self as *const Self as usize,
+                        object_p: p - self.center,
+                        object_normal: outward_normal,
+                        mat: &self.material,
+                        u, v
+                    })
                 }
             }
         }
@@ -0,0 +1,158 @@
+use crate::prelude::{Material, Hit, AABB, HitRecord, Ray, Vec3};
+use crate::ray::RayCone;
+use crate::microfacet::fresnel_dielectric;
+use crate::utils::{reflect, refract, random_in_unit_sphere, Rng, thread_rng};
+
+/// Walks still inside the medium after this many steps are dropped. Russian
+/// roulette ends almost all of them long before, so the energy lost is negligible.
+const MAX_WALK_STEPS: usize = 256;
+
+pub struct Subsurface<T: Hit> {
+    boundary: T,
+    ref_idx: f32,
+    scattering: Vec3,
+    absorption: Vec3,
+}
+
+impl<T: Hit> Subsurface<T> {
+    pub fn new(boundary: T, ref_idx: f32, scattering: Vec3, absorption: Vec3) -> Self {
+        Self { boundary, ref_idx, scattering, absorption }
+    }
+
+    fn walk(&self, mut origin: Vec3, mut direction: Vec3, time: f32) -> Option<(Ray, Vec3)> {
+        let mut rng = thread_rng();
+
+        let extinction = self.scattering + self.absorption;
+        let majorant = extinction.x().max(extinction.y()).max(extinction.z());
+        let mut throughput = Vec3::splat(1.);
+
+        for _step in 0..MAX_WALK_STEPS {
+            let distance = if majorant > 0. {
+                -(1. - rng.gen::<f32>()).ln() / majorant
+            } else {
+                std::f32::INFINITY
+            };
+            let ray = Ray { origin, direction, time, cone: RayCone::default() };
+
+            match self.boundary.hit(&ray, 0.0001, distance) {
+                Some(exit) => {
+                    origin = exit.p;
+
+                    let cos_out = -Vec3::dot(direction, exit.normal);
+                    let escaped = if rng.gen::<f32>() < fresnel_dielectric(cos_out, 1. / self.ref_idx) {
+                        None
+                    } else {
+                        refract(direction, exit.normal, self.ref_idx)
+                    };
+
+                    match escaped {
+                        Some(refracted) => {
+                            let scattered = Ray { origin, direction: refracted, time, cone: RayCone::default() };
+                            return Some((scattered, throughput))
+                        },
+                        None => direction = reflect(direction, exit.normal),
+                    }
+                },
+                None if majorant <= 0. => return None,
+                None => {
+                    origin = ray.point_at_parameter(distance);
+
+                    // Real and null collisions are picked by the throughput-weighted extinction,
+                    // so the summed throughput can only shrink and no channel blows up.
+                    let sum = |v: Vec3| v.x() + v.y() + v.z();
+                    let collision_prob = sum(extinction * throughput) / (majorant * sum(throughput));
+
+                    if rng.gen::<f32>() < collision_prob {
+                        throughput *= self.scattering / (majorant * collision_prob);
+                        direction = random_in_unit_sphere(&mut rng).unit();
+                    } else {
+                        throughput *= (Vec3::splat(majorant) - extinction) / (majorant * (1. - collision_prob));
+                    }
+
+                    let survival = throughput.x().max(throughput.y()).max(throughput.z()).min(1.);
+                    if survival < 1. {
+                        if rng.gen::<f32>() >= survival {
+                            return None
+                        }
+                        throughput /= survival;
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<T: Hit> Material for Subsurface<T> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let unit_direction = r_in.direction.unit();
+        let cos_in = -Vec3::dot(unit_direction, rec.normal);
+
+        if !rec.front_face {
+            return self.walk(rec.p, unit_direction, r_in.time)
+        }
+
+        let entered = if rand::random::<f32>() < fresnel_dielectric(cos_in, self.ref_idx) {
+            None
+        } else {
+            refract(unit_direction, rec.normal, 1. / self.ref_idx)
+        };
+
+        match entered {
+            Some(refracted) => self.walk(rec.p, refracted.unit(), r_in.time),
+            None => {
+                let scattered = Ray {
+                    origin: rec.p,
+                    direction: reflect(unit_direction, rec.normal),
+                    time: r_in.time,
+                    cone: r_in.cone,
+                };
+                Some((scattered, Vec3::splat(1.)))
+            }
+        }
+    }
+}
+
+impl<T: Hit> Hit for Subsurface<T> {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<HitRecord<'_>> {
+        let mut rec = self.boundary.hit(ray, t_min, t_max)?;
+        rec.mat = self;
+        Some(rec)
+    }
+
+    fn bounding_box(&self, t0: f32, t1: f32) -> Option<AABB> {
+        self.boundary.bounding_box(t0, t1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hit::Sphere;
+    use crate::material::{Lambertian, MaterialBuilder};
+
+    #[test]
+    fn non_absorbing_walk_conserves_energy() {
+        let sphere = Sphere::builder()
+            .center((0, 0, 0))
+            .radius(1)
+            .material(Lambertian::colored((1, 1, 1)));
+        let medium = Subsurface::new(sphere, 1., Vec3::new(1., 2., 4.), Vec3::splat(0.));
+
+        let walks = 20_000;
+        let mut total = Vec3::splat(0.);
+        for _ in 0..walks {
+            if let Some((_, throughput)) = medium.walk(Vec3::splat(0.), Vec3::new(1., 0., 0.), 0.) {
+                assert!(throughput.x() + throughput.y() + throughput.z() <= 3.001, "{:?}", throughput);
+                total += throughput;
+            }
+        }
+        let mean = total / walks as f32;
+
+        for &channel in &[mean.x(), mean.y(), mean.z()] {
+            assert!(channel <= 1.03, "walk gained energy: {:?}", mean);
+            assert!(channel >= 0.97, "walk lost energy: {:?}", mean);
+        }
+    }
+}
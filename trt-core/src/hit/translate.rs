@@ -17,6 +17,7 @@ impl<T: Hit> Hit for Translate<T> {
             origin: ray.origin - self.offset,
             direction: ray.direction,
             time: ray.time,
+            cone: ray.cone,
         };
         let mut rec = self.wrapped.hit(&moved_ray, t_min, t_max)?;
         rec.p += self.offset;
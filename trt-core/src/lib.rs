@@ -2,13 +2,17 @@
 #![feature(type_alias_impl_trait)]
 
 mod utils;
+mod microfacet;
 
 pub mod aabb;
+pub mod atmosphere;
 pub mod camera;
 pub mod color;
+pub mod density;
 pub mod dimension;
 pub mod hit;
 pub mod material;
+pub mod noise;
 pub mod perlin;
 pub mod prelude;
 pub mod ray;
@@ -0,0 +1,71 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::texture::Constant;
+use crate::microfacet::{roughness_to_alpha, sample_ggx_anisotropic, reflection_weight_anisotropic, fresnel_conductor};
+use crate::utils::{reflect, thread_rng, Onb};
+use super::conductor::{ALUMINIUM_ETA, ALUMINIUM_K};
+
+pub struct Anisotropic<Rx = Constant, Ry = Constant> {
+    eta: Vec3,
+    k: Vec3,
+    roughness_x: Rx,
+    roughness_y: Ry,
+    rotation: f32,
+}
+
+impl<Rx: Texture, Ry: Texture> Anisotropic<Rx, Ry> {
+    pub fn new(eta: Vec3, k: Vec3, roughness_x: Rx, roughness_y: Ry) -> Self {
+        Self { eta, k, roughness_x, roughness_y, rotation: 0. }
+    }
+
+    pub fn from_albedo(albedo: Vec3, roughness_x: Rx, roughness_y: Ry) -> Self {
+        let r_sqrt = albedo.min(Vec3::splat(0.999)).sqrt();
+        let eta = (Vec3::splat(1.) + r_sqrt) / (Vec3::splat(1.) - r_sqrt);
+
+        Self::new(eta, Vec3::splat(0.), roughness_x, roughness_y)
+    }
+
+    pub fn aluminium(roughness_x: Rx, roughness_y: Ry) -> Self {
+        Self::new(ALUMINIUM_ETA.into(), ALUMINIUM_K.into(), roughness_x, roughness_y)
+    }
+
+    pub fn rotation(mut self, angle: f32) -> Self {
+        self.rotation = (std::f32::consts::PI / 180.) * angle;
+        self
+    }
+
+    fn frame(&self, rec: &HitRecord) -> Onb {
+        let (sin, cos) = self.rotation.sin_cos();
+        let u = cos * rec.tangent + sin * rec.bitangent;
+        let v = Vec3::cross(rec.normal, u);
+
+        Onb { u, v, w: rec.normal }
+    }
+}
+
+impl<Rx: Texture, Ry: Texture> Material for Anisotropic<Rx, Ry> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let alpha_x = roughness_to_alpha(self.roughness_x.value_at(rec).x());
+        let alpha_y = roughness_to_alpha(self.roughness_y.value_at(rec).x());
+
+        let frame = self.frame(rec);
+        let wo = -r_in.direction.unit();
+
+        let h = sample_ggx_anisotropic(&frame, alpha_x, alpha_y, thread_rng());
+        let wi = reflect(-wo, h);
+        let weight = reflection_weight_anisotropic(&frame, wo, wi, h, alpha_x, alpha_y);
+
+        if weight <= 0. {
+            return None
+        }
+
+        let scattered = Ray {
+            origin: rec.p,
+            direction: wi,
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+        let attenuation = weight * fresnel_conductor(Vec3::dot(wo, h), self.eta, self.k);
+
+        Some((scattered, attenuation))
+    }
+}
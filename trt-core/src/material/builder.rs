@@ -1,5 +1,5 @@
-use crate::prelude::{Vec3, Asf32};
-use crate::material::{Metal, Dielectric, Diffuse, Lambertian};
+use crate::prelude::{Vec3, Asf32, Material, Texture};
+use crate::material::{Metal, Dielectric, RoughDielectric, Diffuse, Lambertian, Principled, Conductor, Mix, Coated, OrenNayar, Bump, NormalMap, TwoSided, Anisotropic};
 use crate::texture::Constant;
 
 pub trait MaterialBuilder<Mat>: Sized {
@@ -16,6 +16,20 @@ pub trait MaterialBuilderExt {
         self.material(Dielectric::new(ref_idx))
     }
 
+    fn colored_glass(self, ref_idx: f32, absorption: impl Into<Vec3>) -> Self::Finished
+    where
+        Self: MaterialBuilder<Dielectric>,
+    {
+        self.material(Dielectric::absorbing(ref_idx, absorption.into()))
+    }
+
+    fn rough_dielectric(self, ref_idx: f32, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<RoughDielectric>,
+    {
+        self.material(RoughDielectric::new(ref_idx, Constant::splat(roughness)))
+    }
+
     fn diffuse_color(self, color: impl Into<Vec3>) -> Self::Finished
     where
         Self: MaterialBuilder<Diffuse<Constant>>,
@@ -30,6 +44,13 @@ pub trait MaterialBuilderExt {
         self.material(Lambertian::colored(color))
     }
 
+    fn rough_matte(self, color: impl Into<Vec3>, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<OrenNayar<Constant, Constant>>,
+    {
+        self.material(OrenNayar::colored(color, roughness))
+    }
+
     fn metallic(self, albedo: impl Into<Vec3>) -> Self::Finished
     where
         Self: MaterialBuilder<Metal>,
@@ -43,6 +64,113 @@ pub trait MaterialBuilderExt {
     {
         self.material(Metal::new(albedo.into(), fuzz.as_()))
     }
+
+    fn metallic_ggx(self, albedo: impl Into<Vec3>, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::from_albedo(albedo.into(), Constant::splat(roughness)))
+    }
+
+    fn conductor(self, eta: impl Into<Vec3>, k: impl Into<Vec3>, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::new(eta.into(), k.into(), Constant::splat(roughness)))
+    }
+
+    fn gold(self, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::gold(Constant::splat(roughness)))
+    }
+
+    fn copper(self, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::copper(Constant::splat(roughness)))
+    }
+
+    fn silver(self, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::silver(Constant::splat(roughness)))
+    }
+
+    fn aluminium(self, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Conductor>,
+    {
+        self.material(Conductor::aluminium(Constant::splat(roughness)))
+    }
+
+    fn brushed(self, albedo: impl Into<Vec3>, roughness_x: impl Asf32, roughness_y: impl Asf32, rotation: f32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Anisotropic>,
+    {
+        let anisotropic = Anisotropic::from_albedo(albedo.into(), Constant::splat(roughness_x), Constant::splat(roughness_y))
+            .rotation(rotation);
+
+        self.material(anisotropic)
+    }
+
+    fn principled(self, base_color: impl Into<Vec3>, metallic: impl Asf32, roughness: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Principled>,
+    {
+        let principled = Principled::colored(base_color)
+            .metallic(Constant::splat(metallic))
+            .roughness(Constant::splat(roughness));
+
+        self.material(principled)
+    }
+
+    fn mixed<A, B>(self, a: A, b: B, factor: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Mix<A, B, Constant>>,
+        A: Material,
+        B: Material,
+    {
+        self.material(Mix::new(a, b, Constant::splat(factor)))
+    }
+
+    fn coated<Base>(self, base: Base, ref_idx: f32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Coated<Base>>,
+        Base: Material,
+    {
+        self.material(Coated::new(base, ref_idx))
+    }
+
+    fn two_sided<Front, Back>(self, front: Front, back: Back) -> Self::Finished
+    where
+        Self: MaterialBuilder<TwoSided<Front, Back>>,
+        Front: Material,
+        Back: Material,
+    {
+        self.material(TwoSided::new(front, back))
+    }
+
+    fn bumped<Mat, Tx>(self, material: Mat, height: Tx, strength: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<Bump<Mat, Tx>>,
+        Mat: Material,
+        Tx: Texture,
+    {
+        self.material(Bump::new(material, height, strength.as_()))
+    }
+
+    fn normal_mapped<Mat, Tx>(self, material: Mat, normals: Tx, strength: impl Asf32) -> Self::Finished
+    where
+        Self: MaterialBuilder<NormalMap<Mat, Tx>>,
+        Mat: Material,
+        Tx: Texture,
+    {
+        self.material(NormalMap::new(material, normals, strength.as_()))
+    }
 }
 
 impl<T> MaterialBuilderExt for T { }
@@ -0,0 +1,44 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use super::Interface;
+
+const BUMP_DELTA: f32 = 0.001;
+
+pub struct Bump<Mat, Tx> {
+    material: Mat,
+    height: Tx,
+    strength: f32,
+}
+
+impl<Mat: Material, Tx: Texture> Bump<Mat, Tx> {
+    pub fn new(material: Mat, height: Tx, strength: f32) -> Self {
+        Self { material, height, strength }
+    }
+
+    fn shade<'a>(&self, rec: &HitRecord<'a>) -> HitRecord<'a> {
+        let height = |u, v, p| self.height.value(u, v, p).x();
+
+        let h0 = height(rec.u, rec.v, rec.p);
+        let du = (height(rec.u + BUMP_DELTA, rec.v, rec.p + BUMP_DELTA * rec.tangent) - h0) / BUMP_DELTA;
+        let dv = (height(rec.u, rec.v + BUMP_DELTA, rec.p + BUMP_DELTA * rec.bitangent) - h0) / BUMP_DELTA;
+
+        rec.shading(rec.normal - self.strength * (du * rec.tangent + dv * rec.bitangent))
+    }
+}
+
+impl<Mat: Material, Tx: Texture> Material for Bump<Mat, Tx> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.material.scatter(r_in, &self.shade(rec))
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.material.emitted(rec)
+    }
+
+    fn interface(&self) -> Option<Interface> {
+        self.material.interface()
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.material.scatter_nested(r_in, &self.shade(rec), outer_ref_idx)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::prelude::{Material, HitRecord, Ray, Vec3};
+use crate::microfacet::fresnel_dielectric;
+use crate::utils::{reflect, Rng, thread_rng};
+use super::Interface;
+
+pub struct Coated<Base> {
+    base: Base,
+    ref_idx: f32,
+}
+
+impl<Base: Material> Coated<Base> {
+    pub fn new(base: Base, ref_idx: f32) -> Self {
+        Self { base, ref_idx }
+    }
+
+    fn coat(&self, r_in: &Ray, rec: &HitRecord, base: impl FnOnce() -> Option<(Ray, Vec3)>) -> Option<(Ray, Vec3)> {
+        let unit_direction = r_in.direction.unit();
+        let cos_in = -Vec3::dot(unit_direction, rec.normal);
+
+        if !rec.front_face {
+            return base()
+        }
+
+        if thread_rng().gen::<f32>() < fresnel_dielectric(cos_in, self.ref_idx) {
+            let scattered = Ray {
+                origin: rec.p,
+                direction: reflect(unit_direction, rec.normal),
+                time: r_in.time,
+                cone: r_in.cone,
+            };
+            return Some((scattered, Vec3::splat(1.)))
+        }
+
+        let (scattered, attenuation) = base()?;
+
+        let cos_out = Vec3::dot(scattered.direction.unit(), rec.normal);
+        let coat_transmission = if cos_out > 0. { 1. - fresnel_dielectric(cos_out, self.ref_idx) } else { 1. };
+
+        Some((scattered, coat_transmission * attenuation))
+    }
+}
+
+impl<Base: Material> Material for Coated<Base> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.coat(r_in, rec, || self.base.scatter(r_in, rec))
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.base.emitted(rec)
+    }
+
+    fn interface(&self) -> Option<Interface> {
+        self.base.interface()
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.coat(r_in, rec, || self.base.scatter_nested(r_in, rec, outer_ref_idx))
+    }
+}
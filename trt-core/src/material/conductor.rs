@@ -0,0 +1,87 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::texture::Constant;
+use crate::material::ThinFilm;
+use crate::microfacet::{roughness_to_alpha, sample_ggx, reflection_weight, fresnel_conductor};
+use crate::utils::{reflect, thread_rng};
+
+pub(crate) const ALUMINIUM_ETA: (f32, f32, f32) = (1.657, 0.880, 0.521);
+pub(crate) const ALUMINIUM_K: (f32, f32, f32) = (9.224, 6.269, 4.837);
+
+pub struct Conductor<R = Constant, Film = Constant> {
+    eta: Vec3,
+    k: Vec3,
+    roughness: R,
+    film: Option<ThinFilm<Film>>,
+}
+
+impl<R: Texture> Conductor<R> {
+    pub fn new(eta: Vec3, k: Vec3, roughness: R) -> Self {
+        Self { eta, k, roughness, film: None }
+    }
+
+    pub fn from_albedo(albedo: Vec3, roughness: R) -> Self {
+        let r_sqrt = albedo.min(Vec3::splat(0.999)).sqrt();
+        let eta = (Vec3::splat(1.) + r_sqrt) / (Vec3::splat(1.) - r_sqrt);
+
+        Self::new(eta, Vec3::splat(0.), roughness)
+    }
+
+    pub fn gold(roughness: R) -> Self {
+        Self::new(Vec3::new(0.143, 0.374, 1.442), Vec3::new(3.983, 2.385, 1.603), roughness)
+    }
+
+    pub fn copper(roughness: R) -> Self {
+        Self::new(Vec3::new(0.200, 0.924, 1.102), Vec3::new(3.912, 2.452, 2.142), roughness)
+    }
+
+    pub fn silver(roughness: R) -> Self {
+        Self::new(Vec3::new(0.155, 0.117, 0.138), Vec3::new(4.828, 3.122, 2.147), roughness)
+    }
+
+    pub fn aluminium(roughness: R) -> Self {
+        Self::new(ALUMINIUM_ETA.into(), ALUMINIUM_K.into(), roughness)
+    }
+}
+
+impl<R: Texture, Film: Texture> Conductor<R, Film> {
+    pub fn thin_film<Tx: Texture>(self, ior: f32, thickness: Tx) -> Conductor<R, Tx> {
+        Conductor {
+            eta: self.eta,
+            k: self.k,
+            roughness: self.roughness,
+            film: Some(ThinFilm::new(ior, thickness)),
+        }
+    }
+}
+
+impl<R: Texture, Film: Texture> Material for Conductor<R, Film> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let alpha = roughness_to_alpha(self.roughness.value_at(rec).x());
+
+        let wo = -r_in.direction.unit();
+        let n = rec.normal;
+
+        let h = sample_ggx(n, alpha, thread_rng());
+        let wi = reflect(-wo, h);
+        let weight = reflection_weight(n, wo, wi, h, alpha);
+
+        if weight <= 0. {
+            return None
+        }
+
+        let scattered = Ray {
+            origin: rec.p,
+            direction: wi,
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+        let cos_oh = Vec3::dot(wo, h);
+        let reflectance = match &self.film {
+            Some(film) => film.reflectance(rec, cos_oh, self.eta, self.k),
+            None => fresnel_conductor(cos_oh, self.eta, self.k),
+        };
+        let attenuation = weight * reflectance;
+
+        Some((scattered, attenuation))
+    }
+}
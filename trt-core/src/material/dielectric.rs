@@ -1,48 +1,101 @@
-use crate::prelude::{Material, HitRecord, Ray, Vec3};
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::material::{Interface, ThinFilm};
+use crate::texture::Constant;
 use crate::utils::{reflect, refract, schlick};
 
-pub struct Dielectric {
+pub struct Dielectric<Film = Constant> {
     ref_idx: f32,
+    absorption: Vec3,
+    priority: u32,
+    film: Option<ThinFilm<Film>>,
 }
 
 impl Dielectric {
     pub fn new(ref_idx: f32) -> Self {
-        Self { ref_idx }
+        Self::absorbing(ref_idx, Vec3::splat(0.))
+    }
+
+    pub fn absorbing(ref_idx: f32, absorption: Vec3) -> Self {
+        Self { ref_idx, absorption, priority: 0, film: None }
     }
 }
 
-impl Material for Dielectric {
-    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+impl<Film: Texture> Dielectric<Film> {
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// The film coats the outside of the surface: only front-face hits see it,
+    /// rays leaving the medium through a back face use the bare interface.
+    pub fn thin_film<Tx: Texture>(self, ior: f32, thickness: Tx) -> Dielectric<Tx> {
+        Dielectric {
+            ref_idx: self.ref_idx,
+            absorption: self.absorption,
+            priority: self.priority,
+            film: Some(ThinFilm::new(ior, thickness)),
+        }
+    }
+
+    fn scatter_relative(&self, r_in: &Ray, rec: &HitRecord, relative_idx: f32) -> (Ray, Vec3) {
         let reflected = reflect(r_in.direction, rec.normal);
-        let attenuation = Vec3::splat(1.);
-
-        let (outward_normal, ni_over_nt, cosine) =
-            if Vec3::dot(r_in.direction, rec.normal) > 0. {
-                let cosine = self.ref_idx * Vec3::dot(r_in.direction, rec.normal) / r_in.direction.len();
-                (-rec.normal, self.ref_idx, cosine)
-            } else {
-                let cosine = -Vec3::dot(r_in.direction, rec.normal) / r_in.direction.len();
-                (rec.normal, 1.0 / self.ref_idx, cosine)
-            };
+
+        let cos_in = -Vec3::dot(r_in.direction, rec.normal) / r_in.direction.len();
+        let (ni_over_nt, cosine) = if rec.front_face {
+            (1.0 / relative_idx, cos_in)
+        } else {
+            (relative_idx, relative_idx * cos_in)
+        };
+
+        let reflectance = match &self.film {
+            Some(film) if rec.front_face => film.reflectance(rec, cos_in, Vec3::splat(relative_idx), Vec3::splat(0.)),
+            _ => Vec3::splat(schlick(cosine, relative_idx)),
+        };
+        let reflect_prob = (reflectance.x() + reflectance.y() + reflectance.z()) / 3.;
 
         let prob = rand::random::<f32>();
 
-        if let Some(refracted) = refract(r_in.direction, outward_normal, ni_over_nt) {
-            if prob >= schlick(cosine, self.ref_idx) {
+        if let Some(refracted) = refract(r_in.direction, rec.normal, ni_over_nt) {
+            if prob >= reflect_prob {
                 let scattered = Ray {
                     origin: rec.p,
                     direction: refracted,
                     time: 0.,
+                    cone: r_in.cone,
                 };
-                return Some((scattered, attenuation))
+                let weight = (Vec3::splat(1.) - reflectance) / (1. - reflect_prob);
+                return (scattered, weight)
             }
+
+            let scattered = Ray {
+                origin: rec.p,
+                direction: reflected,
+                time: 0.,
+                cone: r_in.cone,
+            };
+            return (scattered, reflectance / reflect_prob)
         }
 
         let scattered = Ray {
             origin: rec.p,
             direction: reflected,
             time: 0.,
+            cone: r_in.cone,
         };
-        Some((scattered, attenuation))
+        (scattered, Vec3::splat(1.))
+    }
+}
+
+impl<Film: Texture> Material for Dielectric<Film> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.scatter_nested(r_in, rec, 1.)
+    }
+
+    fn interface(&self) -> Option<Interface> {
+        Some(Interface::new(self.ref_idx, self.priority, self.absorption))
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        Some(self.scatter_relative(r_in, rec, self.ref_idx / outer_ref_idx))
     }
 }
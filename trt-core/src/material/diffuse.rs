@@ -24,7 +24,7 @@ impl<T: Texture> Material for Diffuse<T> {
         None
     }
 
-    fn emitted(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
-        self.emit.value(u, v, p)
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.emit.value_at(rec)
     }
 }
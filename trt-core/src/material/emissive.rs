@@ -0,0 +1,22 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+
+pub struct Emissive<Mat, Tx> {
+    material: Mat,
+    emission: Tx,
+}
+
+impl<Mat: Material, Tx: Texture> Emissive<Mat, Tx> {
+    pub fn new(material: Mat, emission: Tx) -> Self {
+        Self { material, emission }
+    }
+}
+
+impl<Mat: Material, Tx: Texture> Material for Emissive<Mat, Tx> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.material.scatter(r_in, rec)
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.emission.value_at(rec) + self.material.emitted(rec)
+    }
+}
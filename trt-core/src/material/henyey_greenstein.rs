@@ -0,0 +1,47 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::utils::{Onb, Rng, thread_rng};
+
+use std::f32::consts::PI;
+
+pub struct HenyeyGreenstein<T: Texture> {
+    albedo: T,
+    g: f32,
+}
+
+impl<T: Texture> HenyeyGreenstein<T> {
+    pub fn new(albedo: T, g: f32) -> Self {
+        Self { albedo, g: g.max(-0.999).min(0.999) }
+    }
+
+    fn sample_cos_theta(&self, xi: f32) -> f32 {
+        let g = self.g;
+
+        if g.abs() < 1e-3 {
+            return 1. - 2. * xi
+        }
+
+        let term = (1. - g * g) / (1. - g + 2. * g * xi);
+        ((1. + g * g - term * term) / (2. * g)).max(-1.).min(1.)
+    }
+}
+
+impl<T: Texture> Material for HenyeyGreenstein<T> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let mut rng = thread_rng();
+
+        let cos_theta = self.sample_cos_theta(rng.gen::<f32>());
+        let sin_theta = (1. - cos_theta * cos_theta).max(0.).sqrt();
+        let phi = 2. * PI * rng.gen::<f32>();
+
+        let local = Vec3::new(phi.cos() * sin_theta, phi.sin() * sin_theta, cos_theta);
+
+        let scattered = Ray {
+            origin: rec.p,
+            direction: Onb::from_w(r_in.direction).local(local),
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+        let attenuation = self.albedo.value_at(rec);
+        Some((scattered, attenuation))
+    }
+}
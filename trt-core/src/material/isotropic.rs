@@ -16,8 +16,9 @@ impl<T: Texture> Material for Isotropic<T> {
             origin: rec.p,
             direction: random_in_unit_sphere(rand::thread_rng()),
             time: r_in.time,
+            cone: r_in.cone,
         };
-        let attenuation = self.albedo.value(rec.u, rec.v, rec.p);
+        let attenuation = self.albedo.value_at(rec);
         Some((scattered, attenuation))
     }
 }
@@ -27,8 +27,9 @@ impl<T: Texture> Material for Lambertian<T> {
             origin: rec.p,
             direction: target - rec.p,
             time: r_in.time,
+            cone: r_in.cone,
         };
-        let attenuation = self.albedo.value(rec.u, rec.v, rec.p);
+        let attenuation = self.albedo.value_at(rec);
         Some((scattered, attenuation))
     }
 }
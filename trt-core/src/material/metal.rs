@@ -21,7 +21,8 @@ impl Material for Metal {
         let scattered = Ray {
             origin: rec.p,
             direction: reflected + self.fuzz * random_in_unit_sphere(rand::thread_rng()),
-            time: 0.
+            time: 0.,
+            cone: r_in.cone,
         };
         let attenuation = self.albedo;
         if Vec3::dot(scattered.direction, rec.normal) > 0. {
@@ -0,0 +1,46 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::utils::{Rng, thread_rng};
+use super::Interface;
+
+pub struct Mix<A, B, Factor> {
+    a: A,
+    b: B,
+    factor: Factor,
+}
+
+impl<A: Material, B: Material, Factor: Texture> Mix<A, B, Factor> {
+    pub fn new(a: A, b: B, factor: Factor) -> Self {
+        Self { a, b, factor }
+    }
+
+    fn factor(&self, value: Vec3) -> f32 {
+        value.x().max(0.).min(1.)
+    }
+
+    fn pick(&self, rec: &HitRecord) -> &dyn Material {
+        if thread_rng().gen::<f32>() < self.factor(self.factor.value_at(rec)) {
+            &self.b
+        } else {
+            &self.a
+        }
+    }
+}
+
+impl<A: Material, B: Material, Factor: Texture> Material for Mix<A, B, Factor> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.pick(rec).scatter(r_in, rec)
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        let factor = self.factor(self.factor.value_at(rec));
+        (1. - factor) * self.a.emitted(rec) + factor * self.b.emitted(rec)
+    }
+
+    fn interface(&self) -> Option<Interface> {
+        self.a.interface().or_else(|| self.b.interface())
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.pick(rec).scatter_nested(r_in, rec, outer_ref_idx)
+    }
+}
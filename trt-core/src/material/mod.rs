@@ -4,17 +4,29 @@ use std::rc::Rc;
 
 pub trait Material {
     fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)>;
-    fn emitted(&self, _u: f32, _v: f32, _p: Vec3) -> Vec3 {
+    fn emitted(&self, _rec: &HitRecord) -> Vec3 {
         Vec3::splat(0.)
     }
+    fn interface(&self) -> Option<Interface> {
+        None
+    }
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, _outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.scatter(r_in, rec)
+    }
 }
 
 impl<T: Material + ?Sized> Material for Arc<T> {
     fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
         self.as_ref().scatter(r_in, rec)
     }
-    fn emitted(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
-        self.as_ref().emitted(u, v, p)
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.as_ref().emitted(rec)
+    }
+    fn interface(&self) -> Option<Interface> {
+        self.as_ref().interface()
+    }
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.as_ref().scatter_nested(r_in, rec, outer_ref_idx)
     }
 }
 
@@ -22,25 +34,73 @@ impl<T: Material + ?Sized> Material for Rc<T> {
     fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
         self.as_ref().scatter(r_in, rec)
     }
-    fn emitted(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
-        self.as_ref().emitted(u, v, p)
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.as_ref().emitted(rec)
+    }
+    fn interface(&self) -> Option<Interface> {
+        self.as_ref().interface()
+    }
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.as_ref().scatter_nested(r_in, rec, outer_ref_idx)
     }
 }
 
+pub mod nested;
+pub use nested::Interface;
+
 mod metal;
 pub use metal::Metal;
 
+mod thin_film;
+pub use thin_film::ThinFilm;
+
 mod dielectric;
 pub use dielectric::Dielectric;
 
+mod rough_dielectric;
+pub use rough_dielectric::RoughDielectric;
+
 mod lambertian;
 pub use lambertian::Lambertian;
 
+mod oren_nayar;
+pub use oren_nayar::OrenNayar;
+
 mod diffuse;
 pub use diffuse::Diffuse;
 
 mod isotropic;
 pub use isotropic::Isotropic;
 
+mod emissive;
+pub use emissive::Emissive;
+
+mod henyey_greenstein;
+pub use henyey_greenstein::HenyeyGreenstein;
+
+mod conductor;
+pub use conductor::Conductor;
+
+mod anisotropic;
+pub use anisotropic::Anisotropic;
+
+mod principled;
+pub use principled::Principled;
+
+mod mix;
+pub use mix::Mix;
+
+mod coated;
+pub use coated::Coated;
+
+mod two_sided;
+pub use two_sided::TwoSided;
+
+mod bump;
+pub use bump::Bump;
+
+mod normal_map;
+pub use normal_map::NormalMap;
+
 pub mod builder;
 pub use builder::{MaterialBuilder, MaterialBuilderExt};
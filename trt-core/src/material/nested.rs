@@ -0,0 +1,139 @@
+use crate::prelude::Vec3;
+
+/// Media are keyed on the boundary a ray crossed (`HitRecord::boundary`), not on the material,
+/// so overlapping shapes that share one material still nest as separate media.
+#[derive(Debug, Clone, Copy)]
+pub struct Interface {
+    boundary: usize,
+    pub ref_idx: f32,
+    pub priority: u32,
+    pub absorption: Vec3,
+}
+
+impl Interface {
+    pub fn new(ref_idx: f32, priority: u32, absorption: Vec3) -> Self {
+        Self {
+            boundary: 0,
+            ref_idx,
+            priority,
+            absorption,
+        }
+    }
+
+    pub fn bound_to(self, boundary: usize) -> Self {
+        Self { boundary, ..self }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MediumStack(Vec<Interface>);
+
+impl MediumStack {
+    fn dominant(&self, excluded: Option<&Interface>) -> Option<&Interface> {
+        self.0.iter()
+            .filter(|medium| excluded.map_or(true, |excluded| medium.boundary != excluded.boundary))
+            .max_by_key(|medium| medium.priority)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_true_intersection(&self, interface: &Interface) -> bool {
+        self.dominant(Some(interface))
+            .map_or(true, |medium| interface.priority >= medium.priority)
+    }
+
+    pub fn outer_ref_idx(&self, interface: &Interface) -> f32 {
+        self.dominant(Some(interface))
+            .map_or(1., |medium| medium.ref_idx)
+    }
+
+    pub fn transmittance(&self, distance: f32) -> Vec3 {
+        match self.dominant(None) {
+            Some(medium) => (-distance * medium.absorption).exp(),
+            None => Vec3::splat(1.),
+        }
+    }
+
+    pub fn cross(&mut self, interface: Interface) {
+        match self.0.iter().rposition(|medium| medium.boundary == interface.boundary) {
+            Some(idx) => { self.0.remove(idx); },
+            None => self.0.push(interface),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hit::Sphere;
+    use crate::material::{Dielectric, MaterialBuilder};
+    use crate::prelude::{Hit, Ray};
+    use crate::ray::RayCone;
+    use std::rc::Rc;
+
+    #[test]
+    fn equal_priority_prefers_innermost() {
+        let water = Interface::new(1.33, 0, Vec3::splat(0.1)).bound_to(1);
+        let glass = Interface::new(1.5, 0, Vec3::splat(0.5)).bound_to(2);
+        let bubble = Interface::new(1., 0, Vec3::splat(0.)).bound_to(3);
+
+        let mut media = MediumStack::default();
+        media.cross(water);
+        media.cross(glass);
+
+        assert!((media.outer_ref_idx(&bubble) - 1.5).abs() < 1e-6);
+        assert!((media.outer_ref_idx(&glass) - 1.33).abs() < 1e-6);
+        assert!((media.transmittance(1.).x() - (-0.5f32).exp()).abs() < 1e-6);
+
+        media.cross(glass);
+        assert!((media.outer_ref_idx(&bubble) - 1.33).abs() < 1e-6);
+    }
+
+    #[test]
+    fn higher_priority_dominates() {
+        let water = Interface::new(1.33, 1, Vec3::splat(0.)).bound_to(1);
+        let ice = Interface::new(1.31, 0, Vec3::splat(0.)).bound_to(2);
+
+        let mut media = MediumStack::default();
+        media.cross(water);
+
+        assert!(!media.is_true_intersection(&ice));
+        media.cross(ice);
+        assert!((media.outer_ref_idx(&ice) - 1.33).abs() < 1e-6);
+    }
+
+    #[test]
+    fn overlapping_shapes_sharing_a_material_nest() {
+        let glass = Rc::new(Dielectric::absorbing(1.5, Vec3::splat(0.5)));
+        let left = Sphere::builder().center((0, 0, 0)).radius(1.).material(glass.clone());
+        let right = Sphere::builder().center((1, 0, 0)).radius(1.).material(glass);
+
+        let ray = Ray {
+            origin: Vec3::new(-5, 0, 0),
+            direction: Vec3::new(1, 0, 0),
+            time: 0.,
+            cone: RayCone::default(),
+        };
+        let crossing = |shape: &dyn Hit, t_min: f32| {
+            let rec = shape.hit(&ray, t_min, std::f32::MAX).unwrap();
+            (rec.t, rec.mat.interface().unwrap().bound_to(rec.boundary))
+        };
+
+        let mut media = MediumStack::default();
+        let (t, enter_left) = crossing(&left, 0.);
+        media.cross(enter_left);
+        let (t, enter_right) = crossing(&right, t + 0.001);
+        media.cross(enter_right);
+        assert!(media.transmittance(1.).x() < 1.);
+
+        let (t, exit_left) = crossing(&left, t + 0.001);
+        media.cross(exit_left);
+        assert!(media.transmittance(1.).x() < 1.);
+
+        let (_, exit_right) = crossing(&right, t + 0.001);
+        media.cross(exit_right);
+        assert!((media.transmittance(1.).x() - 1.).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use super::Interface;
+
+pub struct NormalMap<Mat, Tx> {
+    material: Mat,
+    normals: Tx,
+    strength: f32,
+}
+
+impl<Mat: Material, Tx: Texture> NormalMap<Mat, Tx> {
+    pub fn new(material: Mat, normals: Tx, strength: f32) -> Self {
+        Self { material, normals, strength }
+    }
+
+    fn shade<'a>(&self, rec: &HitRecord<'a>) -> HitRecord<'a> {
+        let local = 2. * self.normals.value_at(rec) - Vec3::splat(1.);
+
+        let normal = self.strength * (local.x() * rec.tangent + local.y() * rec.bitangent)
+            + local.z().max(0.) * rec.normal;
+
+        if normal.len() > 1e-6 { rec.shading(normal) } else { rec.shading(rec.normal) }
+    }
+}
+
+impl<Mat: Material, Tx: Texture> Material for NormalMap<Mat, Tx> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.material.scatter(r_in, &self.shade(rec))
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.material.emitted(rec)
+    }
+
+    fn interface(&self) -> Option<Interface> {
+        self.material.interface()
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.material.scatter_nested(r_in, &self.shade(rec), outer_ref_idx)
+    }
+}
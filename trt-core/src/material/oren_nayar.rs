@@ -0,0 +1,60 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3, Asf32};
+use crate::texture::Constant;
+use crate::utils::{random_cosine_direction, Onb, thread_rng};
+
+pub struct OrenNayar<T, R> {
+    albedo: T,
+    roughness: R,
+}
+
+impl<T: Texture, R: Texture> OrenNayar<T, R> {
+    pub fn new(albedo: T, roughness: R) -> Self {
+        Self { albedo, roughness }
+    }
+}
+
+pub struct UnboundedTx;
+
+impl OrenNayar<UnboundedTx, UnboundedTx> {
+    pub fn colored(color: impl Into<Vec3>, roughness: impl Asf32) -> OrenNayar<Constant, Constant> {
+        OrenNayar::new(Constant::new(color.into()), Constant::splat(roughness))
+    }
+}
+
+fn projected_cos_phi(wi: Vec3, wo: Vec3, n: Vec3) -> f32 {
+    let wi_tangent = wi - Vec3::dot(wi, n) * n;
+    let wo_tangent = wo - Vec3::dot(wo, n) * n;
+    let lengths = wi_tangent.len() * wo_tangent.len();
+
+    if lengths > 1e-6 { Vec3::dot(wi_tangent, wo_tangent) / lengths } else { 0. }
+}
+
+impl<T: Texture, R: Texture> Material for OrenNayar<T, R> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let wo = -r_in.direction.unit();
+        let n = rec.normal;
+        let wi = Onb::from_w(n).local(random_cosine_direction(thread_rng()));
+
+        let sigma = self.roughness.value_at(rec).x().max(0.);
+        let sigma2 = sigma * sigma;
+        let a = 1. - 0.5 * sigma2 / (sigma2 + 0.33);
+        let b = 0.45 * sigma2 / (sigma2 + 0.09);
+
+        let cos_i = Vec3::dot(wi, n).max(0.);
+        let cos_o = Vec3::dot(wo, n).max(0.);
+        let (cos_alpha, cos_beta) = if cos_i < cos_o { (cos_i, cos_o) } else { (cos_o, cos_i) };
+        let sin_alpha = (1. - cos_alpha * cos_alpha).max(0.).sqrt();
+        let tan_beta = (1. - cos_beta * cos_beta).max(0.).sqrt() / cos_beta.max(1e-4);
+
+        let factor = a + b * projected_cos_phi(wi, wo, n).max(0.) * sin_alpha * tan_beta;
+
+        let scattered = Ray {
+            origin: rec.p,
+            direction: wi,
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+        let attenuation = factor * self.albedo.value_at(rec);
+        Some((scattered, attenuation))
+    }
+}
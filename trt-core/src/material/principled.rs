@@ -0,0 +1,203 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::texture::Constant;
+use crate::microfacet::{roughness_to_alpha, sample_ggx, reflection_weight, transmission_weight, fresnel_schlick, fresnel_dielectric};
+use crate::utils::{reflect, refract, random_cosine_direction, Onb, Rng, thread_rng};
+
+const CLEARCOAT_IOR: f32 = 1.5;
+const CLEARCOAT_ALPHA: f32 = 0.01;
+
+pub struct Principled<
+    Base = Constant,
+    Metallic = Constant,
+    Roughness = Constant,
+    Specular = Constant,
+    Sheen = Constant,
+    Clearcoat = Constant,
+    Transmission = Constant,
+> {
+    base_color: Base,
+    metallic: Metallic,
+    roughness: Roughness,
+    specular: Specular,
+    sheen: Sheen,
+    clearcoat: Clearcoat,
+    transmission: Transmission,
+}
+
+impl<B: Texture> Principled<B> {
+    pub fn new(base_color: B) -> Self {
+        Self {
+            base_color,
+            metallic: Constant::splat(0.),
+            roughness: Constant::splat(0.5),
+            specular: Constant::splat(0.5),
+            sheen: Constant::splat(0.),
+            clearcoat: Constant::splat(0.),
+            transmission: Constant::splat(0.),
+        }
+    }
+}
+
+impl Principled {
+    pub fn colored(color: impl Into<Vec3>) -> Self {
+        Self::new(Constant::new(color.into()))
+    }
+}
+
+impl<B, M, R, S, Sh, C, T> Principled<B, M, R, S, Sh, C, T> {
+    pub fn metallic<Tx: Texture>(self, metallic: Tx) -> Principled<B, Tx, R, S, Sh, C, T> {
+        Principled {
+            base_color: self.base_color,
+            metallic,
+            roughness: self.roughness,
+            specular: self.specular,
+            sheen: self.sheen,
+            clearcoat: self.clearcoat,
+            transmission: self.transmission,
+        }
+    }
+
+    pub fn roughness<Tx: Texture>(self, roughness: Tx) -> Principled<B, M, Tx, S, Sh, C, T> {
+        Principled {
+            base_color: self.base_color,
+            metallic: self.metallic,
+            roughness,
+            specular: self.specular,
+            sheen: self.sheen,
+            clearcoat: self.clearcoat,
+            transmission: self.transmission,
+        }
+    }
+
+    pub fn specular<Tx: Texture>(self, specular: Tx) -> Principled<B, M, R, Tx, Sh, C, T> {
+        Principled {
+            base_color: self.base_color,
+            metallic: self.metallic,
+            roughness: self.roughness,
+            specular,
+            sheen: self.sheen,
+            clearcoat: self.clearcoat,
+            transmission: self.transmission,
+        }
+    }
+
+    pub fn sheen<Tx: Texture>(self, sheen: Tx) -> Principled<B, M, R, S, Tx, C, T> {
+        Principled {
+            base_color: self.base_color,
+            metallic: self.metallic,
+            roughness: self.roughness,
+            specular: self.specular,
+            sheen,
+            clearcoat: self.clearcoat,
+            transmission: self.transmission,
+        }
+    }
+
+    pub fn clearcoat<Tx: Texture>(self, clearcoat: Tx) -> Principled<B, M, R, S, Sh, Tx, T> {
+        Principled {
+            base_color: self.base_color,
+            metallic: self.metallic,
+            roughness: self.roughness,
+            specular: self.specular,
+            sheen: self.sheen,
+            clearcoat,
+            transmission: self.transmission,
+        }
+    }
+
+    pub fn transmission<Tx: Texture>(self, transmission: Tx) -> Principled<B, M, R, S, Sh, C, Tx> {
+        Principled {
+            base_color: self.base_color,
+            metallic: self.metallic,
+            roughness: self.roughness,
+            specular: self.specular,
+            sheen: self.sheen,
+            clearcoat: self.clearcoat,
+            transmission,
+        }
+    }
+}
+
+fn scalar(texture: &impl Texture, rec: &HitRecord) -> f32 {
+    texture.value_at(rec).x().max(0.).min(1.)
+}
+
+fn specular_to_ior(specular: f32) -> f32 {
+    let f0_sqrt = (0.08 * specular).sqrt().min(0.99);
+    (1. + f0_sqrt) / (1. - f0_sqrt)
+}
+
+impl<B, M, R, S, Sh, C, T> Material for Principled<B, M, R, S, Sh, C, T>
+where
+    B: Texture,
+    M: Texture,
+    R: Texture,
+    S: Texture,
+    Sh: Texture,
+    C: Texture,
+    T: Texture,
+{
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let mut rng = thread_rng();
+
+        let base_color = self.base_color.value_at(rec);
+        let metallic = scalar(&self.metallic, rec);
+        let alpha = roughness_to_alpha(scalar(&self.roughness, rec));
+        let ior = specular_to_ior(scalar(&self.specular, rec));
+        let sheen = scalar(&self.sheen, rec);
+        let clearcoat = scalar(&self.clearcoat, rec);
+        let transmission = scalar(&self.transmission, rec);
+
+        let wo = -r_in.direction.unit();
+        let entering = rec.front_face;
+        let n = rec.normal;
+
+        let scattered = |direction| Ray {
+            origin: rec.p,
+            direction,
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+        let glossy = |h: Vec3, alpha: f32, tint: Vec3| {
+            let wi = reflect(-wo, h);
+            let weight = reflection_weight(n, wo, wi, h, alpha);
+            if weight > 0. { Some((scattered(wi), weight * tint)) } else { None }
+        };
+
+        if entering && clearcoat > 0. {
+            let h = sample_ggx(n, CLEARCOAT_ALPHA, &mut rng);
+            let coat_reflectance = clearcoat * fresnel_dielectric(Vec3::dot(wo, h), CLEARCOAT_IOR);
+            if rng.gen::<f32>() < coat_reflectance {
+                return glossy(h, CLEARCOAT_ALPHA, Vec3::splat(1.))
+            }
+        }
+
+        let h = sample_ggx(n, alpha, &mut rng);
+        let cos_oh = Vec3::dot(wo, h);
+
+        if rng.gen::<f32>() < metallic {
+            return glossy(h, alpha, fresnel_schlick(cos_oh, base_color))
+        }
+
+        let eta = if entering { ior } else { 1. / ior };
+        if rng.gen::<f32>() < fresnel_dielectric(cos_oh, eta) {
+            return glossy(h, alpha, Vec3::splat(1.))
+        }
+
+        if !entering || rng.gen::<f32>() < transmission {
+            return match refract(-wo, h, 1. / eta) {
+                Some(refracted) => {
+                    let weight = transmission_weight(n, wo, refracted.unit(), h, alpha);
+                    if weight > 0. { Some((scattered(refracted), weight * base_color.sqrt())) } else { None }
+                },
+                None => glossy(h, alpha, Vec3::splat(1.)),
+            }
+        }
+
+        let wi = Onb::from_w(n).local(random_cosine_direction(&mut rng));
+        let half = (wi + wo).unit();
+        let sheen_weight = sheen * (1. - Vec3::dot(wi, half).max(0.)).powi(5);
+
+        Some((scattered(wi), base_color + Vec3::splat(sheen_weight)))
+    }
+}
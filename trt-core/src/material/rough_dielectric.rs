@@ -0,0 +1,55 @@
+use crate::prelude::{Texture, Material, HitRecord, Ray, Vec3};
+use crate::texture::Constant;
+use crate::microfacet::{roughness_to_alpha, sample_ggx, reflection_weight, transmission_weight, fresnel_dielectric};
+use crate::utils::{reflect, refract, Rng, thread_rng};
+
+pub struct RoughDielectric<R = Constant> {
+    ref_idx: f32,
+    roughness: R,
+}
+
+impl<R: Texture> RoughDielectric<R> {
+    pub fn new(ref_idx: f32, roughness: R) -> Self {
+        Self { ref_idx, roughness }
+    }
+}
+
+impl<R: Texture> Material for RoughDielectric<R> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        let mut rng = thread_rng();
+        let alpha = roughness_to_alpha(self.roughness.value_at(rec).x());
+
+        let wo = -r_in.direction.unit();
+        let n = rec.normal;
+        let eta = if rec.front_face { self.ref_idx } else { 1. / self.ref_idx };
+
+        let h = sample_ggx(n, alpha, &mut rng);
+        let fresnel = fresnel_dielectric(Vec3::dot(wo, h), eta);
+
+        let transmitted = if rng.gen::<f32>() < fresnel { None } else { refract(-wo, h, 1. / eta) };
+
+        let (direction, weight) = match transmitted {
+            Some(refracted) => {
+                let refracted = refracted.unit();
+                (refracted, transmission_weight(n, wo, refracted, h, alpha))
+            },
+            None => {
+                let reflected = reflect(-wo, h);
+                (reflected, reflection_weight(n, wo, reflected, h, alpha))
+            },
+        };
+
+        if weight <= 0. {
+            return None
+        }
+
+        let scattered = Ray {
+            origin: rec.p,
+            direction,
+            time: r_in.time,
+            cone: r_in.cone,
+        };
+
+        Some((scattered, Vec3::splat(weight)))
+    }
+}
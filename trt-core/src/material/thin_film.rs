@@ -0,0 +1,20 @@
+use crate::prelude::{Texture, HitRecord, Vec3};
+use crate::microfacet::fresnel_thin_film;
+
+pub struct ThinFilm<Tx> {
+    ior: f32,
+    thickness: Tx,
+}
+
+impl<Tx: Texture> ThinFilm<Tx> {
+    /// `thickness` is sampled from the texture's red channel in micrometres,
+    /// and converted to the nanometres used by the interference term.
+    pub fn new(ior: f32, thickness: Tx) -> Self {
+        Self { ior, thickness }
+    }
+
+    pub fn reflectance(&self, rec: &HitRecord, cos_i: f32, eta: Vec3, k: Vec3) -> Vec3 {
+        let thickness_nm = 1000. * self.thickness.value_at(rec).x().max(0.);
+        fresnel_thin_film(cos_i, self.ior, thickness_nm, eta, k)
+    }
+}
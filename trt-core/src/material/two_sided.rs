@@ -0,0 +1,38 @@
+use crate::prelude::{Material, HitRecord, Ray, Vec3};
+use super::Interface;
+
+pub struct TwoSided<Front, Back> {
+    front: Front,
+    back: Back,
+}
+
+impl<Front: Material, Back: Material> TwoSided<Front, Back> {
+    pub fn new(front: Front, back: Back) -> Self {
+        Self { front, back }
+    }
+
+    fn side(&self, rec: &HitRecord) -> &dyn Material {
+        if rec.front_face { &self.front } else { &self.back }
+    }
+}
+
+impl<Front: Material, Back: Material> Material for TwoSided<Front, Back> {
+    fn scatter(&self, r_in: &Ray, rec: &HitRecord) -> Option<(Ray, Vec3)> {
+        self.side(rec).scatter(r_in, rec)
+    }
+
+    fn emitted(&self, rec: &HitRecord) -> Vec3 {
+        self.side(rec).emitted(rec)
+    }
+
+    /// The enclosed medium is defined by the front material alone, so that entering and leaving
+    /// through either side pushes and pops the same interface. The back material still scatters
+    /// back-face hits, but does so relative to the front material's medium.
+    fn interface(&self) -> Option<Interface> {
+        self.front.interface()
+    }
+
+    fn scatter_nested(&self, r_in: &Ray, rec: &HitRecord, outer_ref_idx: f32) -> Option<(Ray, Vec3)> {
+        self.side(rec).scatter_nested(r_in, rec, outer_ref_idx)
+    }
+}
@@ -0,0 +1,229 @@
+use crate::prelude::Vec3;
+use crate::utils::{Onb, Rng};
+
+use std::f32::consts::PI;
+use std::ops::{Add, Sub, Mul, Div};
+
+pub const MIN_ALPHA: f32 = 0.001;
+
+pub fn roughness_to_alpha(roughness: f32) -> f32 {
+    (roughness * roughness).max(MIN_ALPHA)
+}
+
+pub fn smith_g1(cos_v: f32, alpha: f32) -> f32 {
+    let c2 = (cos_v * cos_v).max(1e-8);
+    let tan2 = (1. - c2) / c2;
+    2. / (1. + (1. + alpha * alpha * tan2).sqrt())
+}
+
+pub fn smith_g(cos_o: f32, cos_i: f32, alpha: f32) -> f32 {
+    smith_g1(cos_o, alpha) * smith_g1(cos_i, alpha)
+}
+
+pub fn sample_ggx(n: Vec3, alpha: f32, mut rng: impl Rng) -> Vec3 {
+    let r1 = rng.gen::<f32>();
+    let r2 = rng.gen::<f32>();
+
+    let tan2 = alpha * alpha * r1 / (1. - r1).max(1e-8);
+    let cos_theta = 1. / (1. + tan2).sqrt();
+    let sin_theta = (1. - cos_theta * cos_theta).max(0.).sqrt();
+    let phi = 2. * PI * r2;
+
+    Onb::from_w(n).local(Vec3::new(phi.cos() * sin_theta, phi.sin() * sin_theta, cos_theta))
+}
+
+pub fn sample_ggx_anisotropic(frame: &Onb, alpha_x: f32, alpha_y: f32, mut rng: impl Rng) -> Vec3 {
+    let r1 = rng.gen::<f32>();
+    let r2 = rng.gen::<f32>();
+
+    let slope = (r1 / (1. - r1).max(1e-8)).sqrt();
+    let phi = 2. * PI * r2;
+
+    frame.local(Vec3::new(-alpha_x * slope * phi.cos(), -alpha_y * slope * phi.sin(), 1.)).unit()
+}
+
+pub fn smith_g1_anisotropic(frame: &Onb, v: Vec3, alpha_x: f32, alpha_y: f32) -> f32 {
+    let (x, y, z) = (Vec3::dot(v, frame.u), Vec3::dot(v, frame.v), Vec3::dot(v, frame.w));
+    let z2 = (z * z).max(1e-8);
+    let stretched = (x * x * alpha_x * alpha_x + y * y * alpha_y * alpha_y) / z2;
+    2. / (1. + (1. + stretched).sqrt())
+}
+
+pub fn reflection_weight_anisotropic(frame: &Onb, wo: Vec3, wi: Vec3, h: Vec3, alpha_x: f32, alpha_y: f32) -> f32 {
+    let cos_o = Vec3::dot(frame.w, wo);
+    let cos_i = Vec3::dot(frame.w, wi);
+    let cos_h = Vec3::dot(frame.w, h);
+
+    if cos_o <= 0. || cos_i <= 0. || cos_h <= 0. {
+        return 0.
+    }
+
+    let g = smith_g1_anisotropic(frame, wo, alpha_x, alpha_y) * smith_g1_anisotropic(frame, wi, alpha_x, alpha_y);
+    g * Vec3::dot(wo, h).abs() / (cos_o * cos_h)
+}
+
+pub fn reflection_weight(n: Vec3, wo: Vec3, wi: Vec3, h: Vec3, alpha: f32) -> f32 {
+    let cos_o = Vec3::dot(n, wo);
+    let cos_i = Vec3::dot(n, wi);
+    let cos_h = Vec3::dot(n, h);
+
+    if cos_o <= 0. || cos_i <= 0. || cos_h <= 0. {
+        return 0.
+    }
+
+    smith_g(cos_o, cos_i, alpha) * Vec3::dot(wo, h).abs() / (cos_o * cos_h)
+}
+
+pub fn transmission_weight(n: Vec3, wo: Vec3, wi: Vec3, h: Vec3, alpha: f32) -> f32 {
+    let cos_o = Vec3::dot(n, wo);
+    let cos_i = Vec3::dot(n, wi);
+    let cos_h = Vec3::dot(n, h);
+
+    if cos_o <= 0. || cos_i >= 0. || cos_h <= 0. {
+        return 0.
+    }
+
+    smith_g(cos_o, -cos_i, alpha) * Vec3::dot(wo, h).abs() / (cos_o * cos_h)
+}
+
+pub fn fresnel_schlick(cosine: f32, f0: Vec3) -> Vec3 {
+    let weight = (1. - cosine.max(0.)).powi(5);
+    f0 + (Vec3::splat(1.) - f0) * weight
+}
+
+pub fn fresnel_dielectric(cos_i: f32, eta: f32) -> f32 {
+    let cos_i = cos_i.min(1.).max(-1.).abs();
+    let sin2_t = (1. - cos_i * cos_i) / (eta * eta);
+
+    if sin2_t >= 1. {
+        return 1.
+    }
+
+    let cos_t = (1. - sin2_t).sqrt();
+    let r_parl = (eta * cos_i - cos_t) / (eta * cos_i + cos_t);
+    let r_perp = (cos_i - eta * cos_t) / (cos_i + eta * cos_t);
+
+    0.5 * (r_parl * r_parl + r_perp * r_perp)
+}
+
+pub fn fresnel_conductor(cos_i: f32, eta: Vec3, k: Vec3) -> Vec3 {
+    let cos_i = cos_i.min(1.).max(0.);
+
+    let channel = |eta: f32, k: f32| {
+        let cos2 = cos_i * cos_i;
+        let sin2 = 1. - cos2;
+
+        let t0 = eta * eta - k * k - sin2;
+        let a2_plus_b2 = (t0 * t0 + 4. * eta * eta * k * k).sqrt();
+        let t1 = a2_plus_b2 + cos2;
+        let a = (0.5 * (a2_plus_b2 + t0)).max(0.).sqrt();
+        let t2 = 2. * cos_i * a;
+        let r_s = (t1 - t2) / (t1 + t2);
+
+        let t3 = cos2 * a2_plus_b2 + sin2 * sin2;
+        let t4 = t2 * sin2;
+        let r_p = r_s * (t3 - t4) / (t3 + t4);
+
+        0.5 * (r_p + r_s)
+    };
+
+    Vec3::new(
+        channel(eta.x(), k.x()),
+        channel(eta.y(), k.y()),
+        channel(eta.z(), k.z()),
+    )
+}
+
+const FILM_WAVELENGTHS: [f32; 3] = [650., 532., 450.];
+
+#[derive(Clone, Copy)]
+struct Complex(f32, f32);
+
+impl Complex {
+    fn real(re: f32) -> Self {
+        Self(re, 0.)
+    }
+
+    fn sqrt(self) -> Self {
+        let modulus = (self.0 * self.0 + self.1 * self.1).sqrt();
+        let re = (0.5 * (modulus + self.0)).max(0.).sqrt();
+        let im = (0.5 * (modulus - self.0)).max(0.).sqrt();
+        Self(re, if self.1 < 0. { -im } else { im })
+    }
+
+    fn exp_i(self) -> Self {
+        let scale = (-self.1).exp();
+        Self(scale * self.0.cos(), scale * self.0.sin())
+    }
+
+    fn norm_sqr(self) -> f32 {
+        self.0 * self.0 + self.1 * self.1
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, o: Self) -> Self {
+        Self(self.0 + o.0, self.1 + o.1)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, o: Self) -> Self {
+        Self(self.0 - o.0, self.1 - o.1)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, o: Self) -> Self {
+        Self(self.0 * o.0 - self.1 * o.1, self.0 * o.1 + self.1 * o.0)
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, o: Self) -> Self {
+        let denom = o.norm_sqr().max(1e-12);
+        Self((self.0 * o.0 + self.1 * o.1) / denom, (self.1 * o.0 - self.0 * o.1) / denom)
+    }
+}
+
+pub fn fresnel_thin_film(cos_i: f32, film_ior: f32, thickness: f32, eta: Vec3, k: Vec3) -> Vec3 {
+    let cos_i = cos_i.min(1.).max(0.);
+    let one = Complex::real(1.);
+    let sin2_i = Complex::real(1. - cos_i * cos_i);
+
+    let (n1, cos1) = (one, Complex::real(cos_i));
+    let n2 = Complex::real(film_ior);
+    let cos2 = (one - sin2_i / (n2 * n2)).sqrt();
+
+    let channel = |wavelength: f32, eta: f32, k: f32| {
+        let n3 = Complex(eta, k);
+        let cos3 = (one - sin2_i / (n3 * n3)).sqrt();
+
+        let phase = (n2 * cos2 * Complex::real(4. * PI * thickness / wavelength)).exp_i();
+        let airy = |r12: Complex, r23: Complex| {
+            let r23 = r23 * phase;
+            ((r12 + r23) / (one + r12 * r23)).norm_sqr()
+        };
+
+        let r12_s = (n1 * cos1 - n2 * cos2) / (n1 * cos1 + n2 * cos2);
+        let r23_s = (n2 * cos2 - n3 * cos3) / (n2 * cos2 + n3 * cos3);
+        let r12_p = (n2 * cos1 - n1 * cos2) / (n2 * cos1 + n1 * cos2);
+        let r23_p = (n3 * cos2 - n2 * cos3) / (n3 * cos2 + n2 * cos3);
+
+        (0.5 * (airy(r12_s, r23_s) + airy(r12_p, r23_p))).min(1.)
+    };
+
+    Vec3::new(
+        channel(FILM_WAVELENGTHS[0], eta.x(), k.x()),
+        channel(FILM_WAVELENGTHS[1], eta.y(), k.y()),
+        channel(FILM_WAVELENGTHS[2], eta.z(), k.z()),
+    )
+}
@@ -0,0 +1,123 @@
+use crate::prelude::Vec3;
+use super::NoiseFn;
+
+pub struct Fbm<N> {
+    noise: N,
+    octaves: usize,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl<N: NoiseFn> Fbm<N> {
+    pub fn new(noise: N, octaves: usize) -> Self {
+        Self {
+            noise,
+            octaves: octaves.max(1),
+            lacunarity: 2.,
+            gain: 0.5,
+        }
+    }
+
+    pub fn lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    pub fn gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+}
+
+impl<N: NoiseFn> NoiseFn for Fbm<N> {
+    fn noise(&self, p: Vec3) -> f32 {
+        let mut sum = 0.;
+        let mut total = 0.;
+        let mut frequency = 1.;
+        let mut amplitude = 1.;
+
+        for _ in 0..self.octaves {
+            sum += amplitude * self.noise.noise(frequency * p);
+            total += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        sum / total
+    }
+}
+
+pub struct Ridged<N> {
+    noise: N,
+    octaves: usize,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl<N: NoiseFn> Ridged<N> {
+    pub fn new(noise: N, octaves: usize) -> Self {
+        Self {
+            noise,
+            octaves: octaves.max(1),
+            lacunarity: 2.,
+            gain: 0.5,
+        }
+    }
+
+    pub fn lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    pub fn gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+}
+
+impl<N: NoiseFn> NoiseFn for Ridged<N> {
+    fn noise(&self, p: Vec3) -> f32 {
+        let mut sum = 0.;
+        let mut total = 0.;
+        let mut frequency = 1.;
+        let mut amplitude = 1.;
+        let mut weight = 1.;
+
+        for _ in 0..self.octaves {
+            let ridge = 1. - self.noise.noise(frequency * p).abs();
+            let signal = ridge * ridge * weight;
+            weight = (2. * signal).max(0.).min(1.);
+
+            sum += amplitude * signal;
+            total += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        2. * sum / total - 1.
+    }
+}
+
+pub struct DomainWarp<N, W> {
+    noise: N,
+    warp: W,
+    strength: f32,
+}
+
+impl<N: NoiseFn, W: NoiseFn> DomainWarp<N, W> {
+    pub fn new(noise: N, warp: W, strength: f32) -> Self {
+        Self { noise, warp, strength }
+    }
+}
+
+impl<N: NoiseFn, W: NoiseFn> NoiseFn for DomainWarp<N, W> {
+    fn noise(&self, p: Vec3) -> f32 {
+        let offset = Vec3::new(
+            self.warp.noise(p),
+            self.warp.noise(p + Vec3::new(5.2, 1.3, 2.8)),
+            self.warp.noise(p + Vec3::new(1.7, 9.2, 3.4)),
+        );
+
+        self.noise.noise(p + self.strength * offset)
+    }
+}
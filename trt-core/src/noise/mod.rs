@@ -0,0 +1,30 @@
+use crate::prelude::Vec3;
+use std::sync::Arc;
+use std::rc::Rc;
+
+pub trait NoiseFn {
+    fn noise(&self, p: Vec3) -> f32;
+}
+
+impl<T: NoiseFn + ?Sized> NoiseFn for Arc<T> {
+    fn noise(&self, p: Vec3) -> f32 {
+        self.as_ref().noise(p)
+    }
+}
+
+impl<T: NoiseFn + ?Sized> NoiseFn for Rc<T> {
+    fn noise(&self, p: Vec3) -> f32 {
+        self.as_ref().noise(p)
+    }
+}
+
+pub use crate::perlin::Perlin;
+
+mod simplex;
+pub use simplex::Simplex;
+
+mod worley;
+pub use worley::Worley;
+
+mod fractal;
+pub use fractal::{Fbm, Ridged, DomainWarp};
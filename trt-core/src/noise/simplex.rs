@@ -0,0 +1,94 @@
+use crate::prelude::Vec3;
+use crate::perlin::perlin_generate_perm;
+use crate::utils::Rng;
+use super::NoiseFn;
+use rand::{SeedableRng, rngs::StdRng};
+
+const GRADIENTS: [[f32; 3]; 12] = [
+    [1., 1., 0.], [-1., 1., 0.], [1., -1., 0.], [-1., -1., 0.],
+    [1., 0., 1.], [-1., 0., 1.], [1., 0., -1.], [-1., 0., -1.],
+    [0., 1., 1.], [0., -1., 1.], [0., 1., -1.], [0., -1., -1.],
+];
+
+const SKEW: f32 = 1. / 3.;
+const UNSKEW: f32 = 1. / 6.;
+
+pub struct Simplex {
+    perm: [usize; 256],
+}
+
+impl Simplex {
+    pub fn new() -> Self {
+        Self::seeded(0)
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: &mut impl Rng) -> Self {
+        Self { perm: perlin_generate_perm(rng) }
+    }
+
+    fn hash(&self, i: usize, j: usize, k: usize) -> usize {
+        self.perm[(self.perm[(self.perm[k & 255] + (j & 255)) & 255] + (i & 255)) & 255] % 12
+    }
+
+    fn corner(&self, gradient: usize, offset: [f32; 3]) -> f32 {
+        let t = 0.6 - offset.iter().map(|x| x * x).sum::<f32>();
+        if t < 0. {
+            return 0.
+        }
+
+        let g = GRADIENTS[gradient];
+        let dot = g[0] * offset[0] + g[1] * offset[1] + g[2] * offset[2];
+        t * t * t * t * dot
+    }
+}
+
+impl Default for Simplex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoiseFn for Simplex {
+    fn noise(&self, p: Vec3) -> f32 {
+        let skew = (p.x() + p.y() + p.z()) * SKEW;
+        let ix = (p.x() + skew).floor();
+        let iy = (p.y() + skew).floor();
+        let iz = (p.z() + skew).floor();
+
+        let unskew = (ix + iy + iz) * UNSKEW;
+        let x0 = [p.x() - (ix - unskew), p.y() - (iy - unskew), p.z() - (iz - unskew)];
+
+        let (first, second) = match (x0[0] >= x0[1], x0[1] >= x0[2], x0[0] >= x0[2]) {
+            (true, true, _) => ([1, 0, 0], [1, 1, 0]),
+            (true, false, true) => ([1, 0, 0], [1, 0, 1]),
+            (true, false, false) => ([0, 0, 1], [1, 0, 1]),
+            (false, false, _) => ([0, 0, 1], [0, 1, 1]),
+            (false, true, false) => ([0, 1, 0], [0, 1, 1]),
+            (false, true, true) => ([0, 1, 0], [1, 1, 0]),
+        };
+
+        let offset = |corner: [usize; 3], unskew: f32| [
+            x0[0] - corner[0] as f32 + unskew,
+            x0[1] - corner[1] as f32 + unskew,
+            x0[2] - corner[2] as f32 + unskew,
+        ];
+
+        let (ix, iy, iz) = (ix as i32 as usize, iy as i32 as usize, iz as i32 as usize);
+        let gradient = |corner: [usize; 3]| self.hash(
+            ix.wrapping_add(corner[0]),
+            iy.wrapping_add(corner[1]),
+            iz.wrapping_add(corner[2]),
+        );
+
+        let n = self.corner(gradient([0, 0, 0]), x0)
+              + self.corner(gradient(first), offset(first, UNSKEW))
+              + self.corner(gradient(second), offset(second, 2. * UNSKEW))
+              + self.corner(gradient([1, 1, 1]), offset([1, 1, 1], 3. * UNSKEW));
+
+        32. * n
+    }
+}
@@ -0,0 +1,64 @@
+use crate::prelude::Vec3;
+use crate::perlin::perlin_generate_perm;
+use crate::utils::Rng;
+use super::NoiseFn;
+use rand::{SeedableRng, rngs::StdRng};
+
+pub struct Worley {
+    perm: [usize; 256],
+    points: [Vec3; 256],
+}
+
+impl Worley {
+    pub fn new() -> Self {
+        Self::seeded(0)
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: &mut impl Rng) -> Self {
+        let perm = perlin_generate_perm(rng);
+        let mut points = [Vec3::splat(0.); 256];
+        for point in &mut points[..] {
+            *point = Vec3::new(rng.gen::<f32>(), rng.gen::<f32>(), rng.gen::<f32>());
+        }
+
+        Self { perm, points }
+    }
+
+    fn feature_point(&self, i: i32, j: i32, k: i32) -> Vec3 {
+        let (i, j, k) = (i as usize, j as usize, k as usize);
+        let hash = self.perm[(self.perm[(self.perm[i & 255] + (j & 255)) & 255] + (k & 255)) & 255];
+        Vec3::new(i as i32 as f32, j as i32 as f32, k as i32 as f32) + self.points[hash]
+    }
+
+    pub fn distance(&self, p: Vec3) -> f32 {
+        let (i, j, k) = (p.x().floor() as i32, p.y().floor() as i32, p.z().floor() as i32);
+
+        let mut closest = std::f32::MAX;
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                for dk in -1..=1 {
+                    let distance = (self.feature_point(i + di, j + dj, k + dk) - p).len();
+                    closest = closest.min(distance);
+                }
+            }
+        }
+
+        closest
+    }
+}
+
+impl Default for Worley {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoiseFn for Worley {
+    fn noise(&self, p: Vec3) -> f32 {
+        2. * self.distance(p).min(1.) - 1.
+    }
+}
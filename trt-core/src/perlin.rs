@@ -1,5 +1,7 @@
 use crate::utils::{Rng, thread_rng};
 use crate::vec3::Vec3;
+use crate::noise::NoiseFn;
+use rand::{SeedableRng, rngs::StdRng};
 
 pub struct Perlin {
     perm_x: [usize; 256],
@@ -10,11 +12,19 @@ pub struct Perlin {
 
 impl Perlin {
     pub fn new() -> Self {
+        Self::from_rng(&mut thread_rng())
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: &mut impl Rng) -> Self {
         Self {
-            ranvec: perlin_generate(),
-            perm_x: perlin_generate_perm(),
-            perm_y: perlin_generate_perm(),
-            perm_z: perlin_generate_perm(),
+            ranvec: perlin_generate(rng),
+            perm_x: perlin_generate_perm(rng),
+            perm_y: perlin_generate_perm(rng),
+            perm_z: perlin_generate_perm(rng),
         }
     }
 
@@ -23,18 +33,18 @@ impl Perlin {
         let v = p.y() - p.y().floor();
         let w = p.z() - p.z().floor();
 
-        let i = p.x().floor() as usize;
-        let j = p.y().floor() as usize;
-        let k = p.z().floor() as usize;
+        let i = p.x().floor() as i32 as usize;
+        let j = p.y().floor() as i32 as usize;
+        let k = p.z().floor() as i32 as usize;
 
         let mut c = [[[Vec3::splat(0.); 2]; 2]; 2];
         for di in 0..2 {
             for dj in 0..2 {
                 for dk in 0..2 {
                     c[di][dj][dk] = self.ranvec[
-                        self.perm_x[(i+di) & 255] ^
-                        self.perm_y[(j+dj) & 255] ^
-                        self.perm_z[(k+dk) & 255]
+                        self.perm_x[i.wrapping_add(di) & 255] ^
+                        self.perm_y[j.wrapping_add(dj) & 255] ^
+                        self.perm_z[k.wrapping_add(dk) & 255]
                     ]
                 }
             }
@@ -56,9 +66,14 @@ impl Perlin {
     }
 }
 
-fn perlin_generate() -> [Vec3; 256] {
+impl NoiseFn for Perlin {
+    fn noise(&self, p: Vec3) -> f32 {
+        Perlin::noise(self, p)
+    }
+}
+
+fn perlin_generate(rng: &mut impl Rng) -> [Vec3; 256] {
     let mut p = [Vec3::splat(0.); 256];
-    let mut rng = thread_rng();
     for v in &mut p[..] {
         let x = 2. * rng.gen::<f32>() - 1.;
         let y = 2. * rng.gen::<f32>() - 1.;
@@ -68,18 +83,16 @@ fn perlin_generate() -> [Vec3; 256] {
     p
 }
 
-fn perlin_generate_perm() -> [usize; 256] {
+pub(crate) fn perlin_generate_perm(rng: &mut impl Rng) -> [usize; 256] {
     let mut p = [0; 256];
     for (i, x) in p.iter_mut().enumerate() {
         *x = i
     }
-    permute(&mut p[..]);
+    permute(&mut p[..], rng);
     p
 }
 
-fn permute(slice: &mut [usize]) {
-    let mut rng = thread_rng();
-
+fn permute(slice: &mut [usize], rng: &mut impl Rng) {
     for i in (1..slice.len()).rev() {
         let target = rng.gen_range(0, i + 1);
         slice.swap(i, target)
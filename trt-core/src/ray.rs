@@ -1,14 +1,25 @@
 use crate::vec3::Vec3;
 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RayCone {
+    pub width: f32,
+    pub spread: f32,
+}
+
 #[derive(Debug, Default)]
 pub struct Ray {
     pub origin: Vec3,
     pub direction: Vec3,
     pub time: f32,
+    pub cone: RayCone,
 }
 
 impl Ray {
     pub fn point_at_parameter(&self, t: f32) -> Vec3 {
         self.origin + t * self.direction
     }
+
+    pub fn width_at(&self, t: f32) -> f32 {
+        self.cone.width + self.cone.spread * t * self.direction.len()
+    }
 }
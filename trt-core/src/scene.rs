@@ -1,4 +1,4 @@
-use crate::{camera::Camera, prelude::{Hit, Vec3, Color}};
+use crate::{atmosphere::Atmosphere, camera::Camera, prelude::{Hit, Vec3, Color}};
 use crate::utils::{compute_color, Rng};
 
 pub struct Scene<World> {
@@ -9,10 +9,12 @@ pub struct Scene<World> {
     pub samples_per_px: u32,
     pub rays_per_sample: u32,
     pub ambiant_color: Vec3,
+    pub atmosphere: Option<Atmosphere>,
 }
 
 impl<World: Hit> Scene<World> {
     pub fn pixel_color(&self, (x, y): (usize, usize), mut rng: impl Rng) -> Color {
+        let pixel_spread = self.camera.pixel_spread(self.height);
         let summed_color = (0..self.samples_per_px)
             .fold(Vec3::splat(0), |current_color, _r| {
                 let u = (x as f32 + rng.gen::<f32>()) / self.width as f32;
@@ -20,7 +22,7 @@ impl<World: Hit> Scene<World> {
 
                 let ray = self.camera.get_ray(u, v);
 
-                current_color + compute_color(ray, &self.world, self.ambiant_color, self.rays_per_sample as _)
+                current_color + compute_color(ray, &self.world, self.atmosphere.as_ref(), self.ambiant_color, self.rays_per_sample as _, pixel_spread)
             });
 
         (summed_color / self.samples_per_px as f32)
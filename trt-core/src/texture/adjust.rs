@@ -0,0 +1,113 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+pub struct Invert<Tx> {
+    input: Tx,
+}
+
+impl<Tx: Texture> Invert<Tx> {
+    pub fn new(input: Tx) -> Self {
+        Self { input }
+    }
+}
+
+impl<Tx: Texture> Texture for Invert<Tx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        Vec3::splat(1.) - self.input.value(u, v, p)
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        Vec3::splat(1.) - self.input.value_at(rec)
+    }
+}
+
+pub struct Clamp<Tx> {
+    input: Tx,
+    min: Vec3,
+    max: Vec3,
+}
+
+impl<Tx: Texture> Clamp<Tx> {
+    pub fn new(input: Tx, min: Vec3, max: Vec3) -> Self {
+        Self { input, min, max }
+    }
+}
+
+impl<Tx: Texture> Texture for Clamp<Tx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.input.value(u, v, p).max(self.min).min(self.max)
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.input.value_at(rec).max(self.min).min(self.max)
+    }
+}
+
+pub struct HsvAdjust<Tx> {
+    input: Tx,
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl<Tx: Texture> HsvAdjust<Tx> {
+    pub fn new(input: Tx, hue: f32, saturation: f32, value: f32) -> Self {
+        Self { input, hue, saturation, value }
+    }
+
+    fn adjust(&self, rgb: Vec3) -> Vec3 {
+        let (h, s, v) = rgb_to_hsv(rgb);
+        hsv_to_rgb(
+            (h + self.hue).rem_euclid(1.),
+            (s * self.saturation).max(0.).min(1.),
+            (v * self.value).max(0.),
+        )
+    }
+}
+
+impl<Tx: Texture> Texture for HsvAdjust<Tx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.adjust(self.input.value(u, v, p))
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.adjust(self.input.value_at(rec))
+    }
+}
+
+fn rgb_to_hsv(rgb: Vec3) -> (f32, f32, f32) {
+    let (r, g, b) = (rgb.x(), rgb.y(), rgb.z());
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta <= 0. {
+        0.
+    } else if r >= g && r >= b {
+        ((g - b) / delta).rem_euclid(6.) / 6.
+    } else if g >= b {
+        ((b - r) / delta + 2.) / 6.
+    } else {
+        ((r - g) / delta + 4.) / 6.
+    };
+    let saturation = if max > 0. { delta / max } else { 0. };
+
+    (hue, saturation, max)
+}
+
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> Vec3 {
+    let chroma = value * saturation;
+    let sector = 6. * hue;
+    let second = chroma * (1. - (sector.rem_euclid(2.) - 1.).abs());
+    let offset = value - chroma;
+
+    let (red, green, blue) = match sector as u32 {
+        0 => (chroma, second, 0.),
+        1 => (second, chroma, 0.),
+        2 => (0., chroma, second),
+        3 => (0., second, chroma),
+        4 => (second, 0., chroma),
+        _ => (chroma, 0., second),
+    };
+
+    Vec3::new(red + offset, green + offset, blue + offset)
+}
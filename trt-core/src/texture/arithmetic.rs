@@ -0,0 +1,43 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+pub struct Multiply<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Texture, B: Texture> Multiply<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Texture, B: Texture> Texture for Multiply<A, B> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.a.value(u, v, p) * self.b.value(u, v, p)
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.a.value_at(rec) * self.b.value_at(rec)
+    }
+}
+
+pub struct Sum<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Texture, B: Texture> Sum<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Texture, B: Texture> Texture for Sum<A, B> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.a.value(u, v, p) + self.b.value(u, v, p)
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.a.value_at(rec) + self.b.value_at(rec)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::prelude::{Texture, Vec3};
+use crate::density::DensityField;
+
+const WAVELENGTHS: [f32; 3] = [650e-9, 532e-9, 450e-9];
+
+pub fn blackbody_color(temperature: f32) -> Vec3 {
+    if temperature <= 0. {
+        return Vec3::splat(0.)
+    }
+
+    let planck = |wavelength: f32| {
+        let c2 = 1.4388e-2;
+        1. / (wavelength.powi(5) * ((c2 / (wavelength * temperature)).exp() - 1.))
+    };
+
+    let radiance = Vec3::new(planck(WAVELENGTHS[0]), planck(WAVELENGTHS[1]), planck(WAVELENGTHS[2]));
+    let peak = radiance.x().max(radiance.y()).max(radiance.z());
+
+    if peak > 0. { radiance / peak } else { Vec3::splat(0.) }
+}
+
+pub struct Blackbody<F> {
+    field: F,
+    max_temperature: f32,
+    intensity: f32,
+}
+
+impl<F: DensityField> Blackbody<F> {
+    pub fn new(field: F, max_temperature: f32, intensity: f32) -> Self {
+        Self { field, max_temperature, intensity }
+    }
+}
+
+impl<F: DensityField> Texture for Blackbody<F> {
+    fn value(&self, _u: f32, _v: f32, p: Vec3) -> Vec3 {
+        let max_density = self.field.max_density();
+        if max_density <= 0. {
+            return Vec3::splat(0.)
+        }
+
+        let heat = (self.field.density(p) / max_density).max(0.).min(1.);
+        self.intensity * heat * blackbody_color(heat * self.max_temperature)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::{Texture, Vec3};
+use crate::prelude::{HitRecord, Texture, Vec3};
 
 pub struct Checker<OddTx: Texture, EvenTx: Texture> {
     odd: OddTx,
@@ -23,4 +23,50 @@ impl<OddTx: Texture, EvenTx: Texture> Texture for Checker<OddTx, EvenTx> {
             self.even.value(u, v, p)
         }
     }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        let p = self.repeat_frequency * rec.p;
+        let sines = p.x().sin() * p.y().sin() * p.z().sin();
+
+        if sines < 0. {
+            self.odd.value_at(&HitRecord { p, ..*rec })
+        } else {
+            self.even.value_at(&HitRecord { p, ..*rec })
+        }
+    }
+}
+
+pub struct UvChecker<OddTx: Texture, EvenTx: Texture> {
+    odd: OddTx,
+    even: EvenTx,
+    repeat: (f32, f32),
+}
+
+impl<OddTx: Texture, EvenTx: Texture> UvChecker<OddTx, EvenTx> {
+    pub fn new(odd: OddTx, even: EvenTx, repeat_u: f32, repeat_v: f32) -> Self {
+        Self { odd, even, repeat: (repeat_u, repeat_v) }
+    }
+
+    fn is_odd(&self, u: f32, v: f32) -> bool {
+        let cell = (u * self.repeat.0).floor() + (v * self.repeat.1).floor();
+        cell.rem_euclid(2.) >= 1.
+    }
+}
+
+impl<OddTx: Texture, EvenTx: Texture> Texture for UvChecker<OddTx, EvenTx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        if self.is_odd(u, v) {
+            self.odd.value(u, v, p)
+        } else {
+            self.even.value(u, v, p)
+        }
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        if self.is_odd(rec.u, rec.v) {
+            self.odd.value_at(rec)
+        } else {
+            self.even.value_at(rec)
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::prelude::{Texture, Vec3};
+use crate::prelude::{Texture, Vec3, Asf32};
 
 pub struct Constant {
     color: Vec3,
@@ -8,6 +8,10 @@ impl Constant {
     pub fn new(color: Vec3) -> Self {
         Self { color }
     }
+
+    pub fn splat(value: impl Asf32) -> Self {
+        Self::new(Vec3::splat(value))
+    }
 }
 
 impl Texture for Constant {
@@ -1,36 +1,302 @@
-use crate::prelude::{Texture, Vec3};
+use crate::prelude::{Texture, HitRecord, Vec3};
+use super::WrapMode;
+use std::borrow::Borrow;
 
-pub struct Image {
-    data: Vec<u8>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    Nearest,
+    Bilinear,
+    Trilinear,
+}
+
+impl Default for FilterMode {
+    fn default() -> Self {
+        FilterMode::Nearest
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    Linear,
+    Srgb,
+}
+
+impl Default for ColorSpace {
+    fn default() -> Self {
+        ColorSpace::Linear
+    }
+}
+
+impl ColorSpace {
+    fn decode(self, value: f32) -> f32 {
+        match self {
+            ColorSpace::Linear => value,
+            ColorSpace::Srgb if value <= 0.04045 => value / 12.92,
+            ColorSpace::Srgb => ((value + 0.055) / 1.055).powf(2.4),
+        }
+    }
+}
+
+struct MipLevel {
+    data: Vec<f32>,
     width: usize,
     height: usize,
 }
 
+impl MipLevel {
+    fn downsample(&self, channels: usize, wrap: WrapMode) -> Self {
+        let width = (self.width / 2).max(1);
+        let height = (self.height / 2).max(1);
+        let mut data = Vec::with_capacity(width * height * channels);
+
+        for y in 0..height {
+            for x in 0..width {
+                for c in 0..channels {
+                    let sum: f32 = [(0, 0), (1, 0), (0, 1), (1, 1)].iter()
+                        .map(|&(dx, dy)| self.texel((2 * x + dx) as isize, (2 * y + dy) as isize, channels, wrap)[c])
+                        .sum();
+                    data.push(sum / 4.);
+                }
+            }
+        }
+
+        Self { data, width, height }
+    }
+
+    fn texel(&self, x: isize, y: isize, channels: usize, wrap: WrapMode) -> &[f32] {
+        let x = wrap.wrap_index(x, self.width);
+        let y = wrap.wrap_index(y, self.height);
+
+        let idx = y * self.width + x;
+        &self.data[idx * channels..(idx + 1) * channels]
+    }
+}
+
+pub struct Image {
+    levels: Vec<MipLevel>,
+    channels: usize,
+    filter: FilterMode,
+    wrap: WrapMode,
+}
+
 impl Image {
     pub fn load(data: impl Into<Vec<u8>>, width: usize, height: usize) -> Self {
-        Self {
-            data: data.into(),
+        Self::from_u8(&data.into(), width, height, 3, ColorSpace::Linear)
+    }
+
+    pub fn load_rgba(data: impl Into<Vec<u8>>, width: usize, height: usize) -> Self {
+        Self::from_u8(&data.into(), width, height, 4, ColorSpace::Linear)
+    }
+
+    pub fn from_u8(data: &[u8], width: usize, height: usize, channels: usize, color_space: ColorSpace) -> Self {
+        let data = data.iter().map(|&c| c as f32 / 255.).collect();
+        Self::decoded(data, width, height, channels, color_space)
+    }
+
+    pub fn from_u16(data: &[u16], width: usize, height: usize, channels: usize, color_space: ColorSpace) -> Self {
+        let data = data.iter().map(|&c| c as f32 / 65535.).collect();
+        Self::decoded(data, width, height, channels, color_space)
+    }
+
+    pub fn from_f32(data: impl Into<Vec<f32>>, width: usize, height: usize, channels: usize) -> Self {
+        Self::with_channels(data.into(), width, height, channels)
+    }
+
+    fn decoded(mut data: Vec<f32>, width: usize, height: usize, channels: usize, color_space: ColorSpace) -> Self {
+        let color_channels = match channels {
+            2 | 4 => channels - 1,
+            _ => channels,
+        };
+
+        for texel in data.chunks_mut(channels) {
+            for c in &mut texel[..color_channels] {
+                *c = color_space.decode(*c);
+            }
+        }
+
+        Self::with_channels(data, width, height, channels)
+    }
+
+    fn with_channels(data: Vec<f32>, width: usize, height: usize, channels: usize) -> Self {
+        assert!((1..=4).contains(&channels), "Images must have between 1 and 4 channels");
+
+        let base = MipLevel {
+            data,
             width,
             height,
+        };
+
+        let mut image = Self {
+            levels: vec![base],
+            channels,
+            filter: FilterMode::default(),
+            wrap: WrapMode::Clamp,
+        };
+        image.build_mips();
+        image
+    }
+
+    fn build_mips(&mut self) {
+        self.levels.truncate(1);
+        while let Some(last) = self.levels.last().filter(|l| l.width > 1 || l.height > 1) {
+            let next = last.downsample(self.channels, self.wrap);
+            self.levels.push(next);
+        }
+    }
+
+    pub fn filter(mut self, filter: FilterMode) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// How texels outside the image are fetched when filtering and building mip levels.
+    /// Defaults to `Clamp`; match the wrap mode of an enclosing `UvTransform` to avoid seams when tiling.
+    /// `Border` behaves like `Clamp` here, the border color itself is applied by `UvTransform`.
+    pub fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self.build_mips();
+        self
+    }
+
+    fn nearest(&self, level: usize, u: f32, v: f32) -> [f32; 4] {
+        let level = &self.levels[level];
+        let x = (u * level.width as f32).floor() as isize;
+        let y = ((1. - v) * level.height as f32 - 0.001).floor() as isize;
+
+        self.rgba(level.texel(x, y, self.channels, self.wrap))
+    }
+
+    fn bilinear(&self, level: usize, u: f32, v: f32) -> [f32; 4] {
+        let level = &self.levels[level];
+        let x = u * level.width as f32 - 0.5;
+        let y = (1. - v) * level.height as f32 - 0.5;
+        let (fx, fy) = (x - x.floor(), y - y.floor());
+        let (x, y) = (x.floor() as isize, y.floor() as isize);
+
+        let mut result = [0.; 4];
+        for &(dx, dy, weight) in &[
+            (0, 0, (1. - fx) * (1. - fy)),
+            (1, 0, fx * (1. - fy)),
+            (0, 1, (1. - fx) * fy),
+            (1, 1, fx * fy),
+        ] {
+            let texel = self.rgba(level.texel(x + dx, y + dy, self.channels, self.wrap));
+            for (r, t) in result.iter_mut().zip(&texel) {
+                *r += weight * t;
+            }
+        }
+
+        result
+    }
+
+    fn trilinear(&self, u: f32, v: f32, footprint: f32) -> [f32; 4] {
+        let base = &self.levels[0];
+        let max_level = (self.levels.len() - 1) as f32;
+        let lod = (footprint * base.width.max(base.height) as f32).log2().max(0.).min(max_level);
+
+        let lower = lod.floor();
+        let t = lod - lower;
+        let fine = self.bilinear(lower as usize, u, v);
+        if t == 0. {
+            return fine
+        }
+
+        let coarse = self.bilinear(lower as usize + 1, u, v);
+        let mut result = [0.; 4];
+        for i in 0..4 {
+            result[i] = (1. - t) * fine[i] + t * coarse[i];
+        }
+        result
+    }
+
+    fn rgba(&self, texel: &[f32]) -> [f32; 4] {
+        match self.channels {
+            1 => [texel[0], texel[0], texel[0], 1.],
+            2 => [texel[0], texel[0], texel[0], texel[1]],
+            3 => [texel[0], texel[1], texel[2], 1.],
+            _ => [texel[0], texel[1], texel[2], texel[3]],
+        }
+    }
+
+    fn sample(&self, u: f32, v: f32, footprint: f32) -> [f32; 4] {
+        match self.filter {
+            FilterMode::Nearest => self.nearest(0, u, v),
+            FilterMode::Bilinear => self.bilinear(0, u, v),
+            FilterMode::Trilinear => self.trilinear(u, v, footprint),
+        }
+    }
+
+    pub fn alpha(&self, u: f32, v: f32) -> f32 {
+        self.sample(u, v, 0.)[3]
+    }
+}
+
+#[cfg(feature = "image")]
+impl Image {
+    pub fn decode(bytes: &[u8], color_space: ColorSpace) -> ::image::ImageResult<Self> {
+        use ::image::{DynamicImage, GenericImageView};
+
+        #[cfg(feature = "hdr")]
+        {
+            if let Ok(::image::ImageFormat::Hdr) = ::image::guess_format(bytes) {
+                let decoder = ::image::hdr::HdrDecoder::new(bytes)?;
+                let metadata = decoder.metadata();
+                let data: Vec<f32> = decoder.read_image_hdr()?
+                    .iter()
+                    .flat_map(|px| px.0.iter().copied())
+                    .collect();
+
+                return Ok(Self::from_f32(data, metadata.width as _, metadata.height as _, 3))
+            }
         }
+
+        let img = ::image::load_from_memory(bytes)?;
+        let (width, height) = (img.width() as usize, img.height() as usize);
+
+        let image = match img {
+            DynamicImage::ImageLuma8(buf) => Self::from_u8(&buf, width, height, 1, color_space),
+            DynamicImage::ImageLumaA8(buf) => Self::from_u8(&buf, width, height, 2, color_space),
+            DynamicImage::ImageRgb8(buf) => Self::from_u8(&buf, width, height, 3, color_space),
+            DynamicImage::ImageRgba8(buf) => Self::from_u8(&buf, width, height, 4, color_space),
+            DynamicImage::ImageLuma16(buf) => Self::from_u16(&buf, width, height, 1, color_space),
+            DynamicImage::ImageLumaA16(buf) => Self::from_u16(&buf, width, height, 2, color_space),
+            DynamicImage::ImageRgb16(buf) => Self::from_u16(&buf, width, height, 3, color_space),
+            DynamicImage::ImageRgba16(buf) => Self::from_u16(&buf, width, height, 4, color_space),
+            other => Self::from_u8(&other.into_rgba(), width, height, 4, color_space),
+        };
+
+        Ok(image)
     }
 }
 
 impl Texture for Image {
     fn value(&self, u: f32, v: f32, _p: Vec3) -> Vec3 {
-        let x = (u * self.width as f32).max(0.) as usize;
-        let y = ((1. - v) * self.height as f32 - 0.001).max(0.) as usize;
+        let [r, g, b, _] = self.sample(u, v, 0.);
+        Vec3::new(r, g, b)
+    }
 
-        let x = x.min(self.width - 1);
-        let y = y.min(self.height - 1);
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        let [r, g, b, _] = self.sample(rec.u, rec.v, rec.footprint);
+        Vec3::new(r, g, b)
+    }
+}
 
-        let idx = y * self.width + x;
-        let px = &self.data[idx * 3..];
+pub struct AlphaChannel<I> {
+    image: I,
+}
 
-        let r = px[0] as f32 / 255.;
-        let g = px[1] as f32 / 255.;
-        let b = px[2] as f32 / 255.;
+impl<I: Borrow<Image>> AlphaChannel<I> {
+    pub fn new(image: I) -> Self {
+        Self { image }
+    }
+}
 
-        Vec3::new(r, g, b)
+impl<I: Borrow<Image>> Texture for AlphaChannel<I> {
+    fn value(&self, u: f32, v: f32, _p: Vec3) -> Vec3 {
+        Vec3::splat(self.image.borrow().alpha(u, v))
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        Vec3::splat(self.image.borrow().sample(rec.u, rec.v, rec.footprint)[3])
     }
 }
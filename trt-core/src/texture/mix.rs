@@ -0,0 +1,28 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+pub struct Mix<A, B, Factor> {
+    a: A,
+    b: B,
+    factor: Factor,
+}
+
+impl<A: Texture, B: Texture, Factor: Texture> Mix<A, B, Factor> {
+    pub fn new(a: A, b: B, factor: Factor) -> Self {
+        Self { a, b, factor }
+    }
+}
+
+fn lerp(a: Vec3, b: Vec3, factor: Vec3) -> Vec3 {
+    let factor = factor.max(Vec3::splat(0.)).min(Vec3::splat(1.));
+    (Vec3::splat(1.) - factor) * a + factor * b
+}
+
+impl<A: Texture, B: Texture, Factor: Texture> Texture for Mix<A, B, Factor> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        lerp(self.a.value(u, v, p), self.b.value(u, v, p), self.factor.value(u, v, p))
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        lerp(self.a.value_at(rec), self.b.value_at(rec), self.factor.value_at(rec))
+    }
+}
@@ -1,18 +1,124 @@
-use crate::prelude::Vec3;
+use crate::prelude::{HitRecord, Vec3};
+use std::sync::Arc;
+use std::rc::Rc;
 
 pub trait Texture {
     fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3;
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.value(rec.u, rec.v, rec.p)
+    }
+
+    fn mix<Other: Texture, Factor: Texture>(self, other: Other, factor: Factor) -> Mix<Self, Other, Factor>
+    where
+        Self: Sized
+    {
+        Mix::new(self, other, factor)
+    }
+
+    fn multiply<Other: Texture>(self, other: Other) -> Multiply<Self, Other>
+    where
+        Self: Sized
+    {
+        Multiply::new(self, other)
+    }
+
+    fn add<Other: Texture>(self, other: Other) -> Sum<Self, Other>
+    where
+        Self: Sized
+    {
+        Sum::new(self, other)
+    }
+
+    fn ramp(self, stops: impl Into<Vec<(f32, Vec3)>>) -> ColorRamp<Self>
+    where
+        Self: Sized
+    {
+        ColorRamp::new(self, stops)
+    }
+
+    fn map_scalar<F: Fn(f32) -> Vec3>(self, map: F) -> MapScalar<Self, F>
+    where
+        Self: Sized
+    {
+        MapScalar::new(self, map)
+    }
+
+    fn hsv(self, hue: f32, saturation: f32, value: f32) -> HsvAdjust<Self>
+    where
+        Self: Sized
+    {
+        HsvAdjust::new(self, hue, saturation, value)
+    }
+
+    fn invert(self) -> Invert<Self>
+    where
+        Self: Sized
+    {
+        Invert::new(self)
+    }
+
+    fn clamp(self, min: impl Into<Vec3>, max: impl Into<Vec3>) -> Clamp<Self>
+    where
+        Self: Sized
+    {
+        Clamp::new(self, min.into(), max.into())
+    }
+
+    fn triplanar(self, scale: f32) -> Triplanar<Self>
+    where
+        Self: Sized
+    {
+        Triplanar::new(self, scale)
+    }
+}
+
+impl<T: Texture + ?Sized> Texture for Arc<T> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.as_ref().value(u, v, p)
+    }
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.as_ref().value_at(rec)
+    }
+}
+
+impl<T: Texture + ?Sized> Texture for Rc<T> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.as_ref().value(u, v, p)
+    }
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.as_ref().value_at(rec)
+    }
 }
 
 mod constant;
 pub use constant::Constant;
 
 mod checker;
-pub use checker::Checker;
+pub use checker::{Checker, UvChecker};
 
 mod noise;
-pub use noise::Noise;
+pub use noise::{Noise, NoiseTexture};
+
+mod blackbody;
+pub use blackbody::{Blackbody, blackbody_color};
 
 mod image;
-pub use self::image::Image;
+pub use self::image::{Image, AlphaChannel, ColorSpace, FilterMode};
+
+mod triplanar;
+pub use triplanar::{Triplanar, TriplanarSpace};
+
+mod uv_transform;
+pub use uv_transform::{UvTransform, WrapMode};
+
+mod mix;
+pub use mix::Mix;
+
+mod arithmetic;
+pub use arithmetic::{Multiply, Sum};
+
+mod ramp;
+pub use ramp::{ColorRamp, MapScalar};
 
+mod adjust;
+pub use adjust::{Clamp, HsvAdjust, Invert};
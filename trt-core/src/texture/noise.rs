@@ -1,5 +1,6 @@
 use crate::prelude::{Texture, Vec3};
 use crate::perlin::Perlin;
+use crate::noise::NoiseFn;
 
 pub struct Noise {
     perlin: Perlin,
@@ -8,8 +9,12 @@ pub struct Noise {
 
 impl Noise {
     pub fn from_scale(scale: f32) -> Self {
+        Self::seeded(scale, 0)
+    }
+
+    pub fn seeded(scale: f32, seed: u64) -> Self {
         Self {
-            perlin: Perlin::new(),
+            perlin: Perlin::seeded(seed),
             scale,
         }
     }
@@ -20,3 +25,21 @@ impl Texture for Noise {
         Vec3::splat(1.) * 0.5 * (1. + (self.scale * p.x() + 5. * self.perlin.turb(self.scale * p, 7)).sin())
     }
 }
+
+pub struct NoiseTexture<N> {
+    noise: N,
+    scale: f32,
+}
+
+impl<N: NoiseFn> NoiseTexture<N> {
+    pub fn new(noise: N, scale: f32) -> Self {
+        Self { noise, scale }
+    }
+}
+
+impl<N: NoiseFn> Texture for NoiseTexture<N> {
+    fn value(&self, _u: f32, _v: f32, p: Vec3) -> Vec3 {
+        let n = self.noise.noise(self.scale * p);
+        Vec3::splat((0.5 * (1. + n)).max(0.).min(1.))
+    }
+}
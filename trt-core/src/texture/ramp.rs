@@ -0,0 +1,60 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+pub struct ColorRamp<Tx> {
+    input: Tx,
+    stops: Vec<(f32, Vec3)>,
+}
+
+impl<Tx: Texture> ColorRamp<Tx> {
+    pub fn new(input: Tx, stops: impl Into<Vec<(f32, Vec3)>>) -> Self {
+        let mut stops = stops.into();
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Self { input, stops }
+    }
+
+    fn color(&self, t: f32) -> Vec3 {
+        let upper = self.stops.iter().position(|&(pos, _)| pos > t);
+
+        match upper {
+            None => self.stops.last().map_or(Vec3::splat(0.), |&(_, color)| color),
+            Some(0) => self.stops[0].1,
+            Some(i) => {
+                let (p0, c0) = self.stops[i - 1];
+                let (p1, c1) = self.stops[i];
+                let factor = (t - p0) / (p1 - p0);
+                (1. - factor) * c0 + factor * c1
+            },
+        }
+    }
+}
+
+impl<Tx: Texture> Texture for ColorRamp<Tx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        self.color(self.input.value(u, v, p).x())
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        self.color(self.input.value_at(rec).x())
+    }
+}
+
+pub struct MapScalar<Tx, F> {
+    input: Tx,
+    map: F,
+}
+
+impl<Tx: Texture, F: Fn(f32) -> Vec3> MapScalar<Tx, F> {
+    pub fn new(input: Tx, map: F) -> Self {
+        Self { input, map }
+    }
+}
+
+impl<Tx: Texture, F: Fn(f32) -> Vec3> Texture for MapScalar<Tx, F> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        (self.map)(self.input.value(u, v, p).x())
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        (self.map)(self.input.value_at(rec).x())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriplanarSpace {
+    World,
+    Object,
+}
+
+pub struct Triplanar<Tx> {
+    texture: Tx,
+    scale: f32,
+    sharpness: f32,
+    space: TriplanarSpace,
+}
+
+impl<Tx: Texture> Triplanar<Tx> {
+    pub fn new(texture: Tx, scale: f32) -> Self {
+        Self {
+            texture,
+            scale,
+            sharpness: 4.,
+            space: TriplanarSpace::World,
+        }
+    }
+
+    pub fn sharpness(mut self, sharpness: f32) -> Self {
+        self.sharpness = sharpness;
+        self
+    }
+
+    pub fn space(mut self, space: TriplanarSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    fn projections(&self, p: Vec3) -> [(f32, f32); 3] {
+        let p = self.scale * p;
+        let wrap = |x: f32| x.rem_euclid(1.);
+
+        [
+            (wrap(p.z()), wrap(p.y())),
+            (wrap(p.x()), wrap(p.z())),
+            (wrap(p.x()), wrap(p.y())),
+        ]
+    }
+
+    fn weights(&self, normal: Vec3) -> [f32; 3] {
+        let w = [
+            normal.x().abs().powf(self.sharpness),
+            normal.y().abs().powf(self.sharpness),
+            normal.z().abs().powf(self.sharpness),
+        ];
+        let total = (w[0] + w[1] + w[2]).max(std::f32::EPSILON);
+
+        [w[0] / total, w[1] / total, w[2] / total]
+    }
+}
+
+impl<Tx: Texture> Texture for Triplanar<Tx> {
+    fn value(&self, _u: f32, _v: f32, p: Vec3) -> Vec3 {
+        self.projections(p).iter()
+            .map(|&(u, v)| self.texture.value(u, v, p))
+            .fold(Vec3::splat(0.), |sum, value| sum + value / 3.)
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        let (p, normal) = match self.space {
+            TriplanarSpace::World => (rec.p, rec.normal),
+            TriplanarSpace::Object => (rec.object_p, rec.object_normal),
+        };
+
+        let footprint = rec.spread * self.scale;
+
+        self.projections(p).iter()
+            .zip(&self.weights(normal))
+            .filter(|(_, &weight)| weight > 0.001)
+            .map(|(&(u, v), &weight)| weight * self.texture.value_at(&HitRecord { u, v, footprint, ..*rec }))
+            .fold(Vec3::splat(0.), |sum, value| sum + value)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::prelude::{HitRecord, Texture, Vec3};
+
+#[derive(Debug, Clone, Copy)]
+pub enum WrapMode {
+    Repeat,
+    Mirror,
+    Clamp,
+    Border(Vec3),
+}
+
+impl Default for WrapMode {
+    fn default() -> Self {
+        WrapMode::Repeat
+    }
+}
+
+impl WrapMode {
+    fn wrap(self, x: f32) -> Option<f32> {
+        match self {
+            WrapMode::Repeat => Some(x.rem_euclid(1.)),
+            WrapMode::Mirror => {
+                let x = x.rem_euclid(2.);
+                Some(if x > 1. { 2. - x } else { x })
+            },
+            WrapMode::Clamp => Some(x.max(0.).min(1.)),
+            WrapMode::Border(_) if x < 0. || x > 1. => None,
+            WrapMode::Border(_) => Some(x),
+        }
+    }
+
+    pub(crate) fn wrap_index(self, i: isize, n: usize) -> usize {
+        let n = n as isize;
+        let i = match self {
+            WrapMode::Repeat => i.rem_euclid(n),
+            WrapMode::Mirror => {
+                let i = i.rem_euclid(2 * n);
+                if i >= n { 2 * n - 1 - i } else { i }
+            },
+            WrapMode::Clamp | WrapMode::Border(_) => i.max(0).min(n - 1),
+        };
+        i as usize
+    }
+}
+
+pub struct UvTransform<Tx> {
+    texture: Tx,
+    scale: (f32, f32),
+    offset: (f32, f32),
+    rotation: (f32, f32),
+    wrap: WrapMode,
+}
+
+impl<Tx: Texture> UvTransform<Tx> {
+    pub fn new(texture: Tx) -> Self {
+        Self {
+            texture,
+            scale: (1., 1.),
+            offset: (0., 0.),
+            rotation: (0., 1.),
+            wrap: WrapMode::default(),
+        }
+    }
+
+    pub fn scale(mut self, u: f32, v: f32) -> Self {
+        self.scale = (u, v);
+        self
+    }
+
+    pub fn offset(mut self, u: f32, v: f32) -> Self {
+        self.offset = (u, v);
+        self
+    }
+
+    pub fn rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees.to_radians().sin_cos();
+        self
+    }
+
+    pub fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    fn transform(&self, u: f32, v: f32) -> Option<(f32, f32)> {
+        let (sin, cos) = self.rotation;
+        let (u, v) = (cos * u - sin * v, sin * u + cos * v);
+        let u = self.scale.0 * u + self.offset.0;
+        let v = self.scale.1 * v + self.offset.1;
+
+        Some((self.wrap.wrap(u)?, self.wrap.wrap(v)?))
+    }
+
+    fn border(&self) -> Vec3 {
+        match self.wrap {
+            WrapMode::Border(color) => color,
+            _ => Vec3::splat(0.),
+        }
+    }
+}
+
+impl<Tx: Texture> Texture for UvTransform<Tx> {
+    fn value(&self, u: f32, v: f32, p: Vec3) -> Vec3 {
+        match self.transform(u, v) {
+            Some((u, v)) => self.texture.value(u, v, p),
+            None => self.border(),
+        }
+    }
+
+    fn value_at(&self, rec: &HitRecord) -> Vec3 {
+        match self.transform(rec.u, rec.v) {
+            Some((u, v)) => {
+                let footprint = rec.footprint * self.scale.0.abs().max(self.scale.1.abs());
+                self.texture.value_at(&HitRecord { u, v, footprint, ..*rec })
+            },
+            None => self.border(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::texture::{FilterMode, Image};
+
+    fn close(a: Option<f32>, b: f32) -> bool {
+        a.map_or(false, |a| (a - b).abs() < 1e-5)
+    }
+
+    #[test]
+    fn repeat_wraps_into_unit_range() {
+        assert!(close(WrapMode::Repeat.wrap(0.25), 0.25));
+        assert!(close(WrapMode::Repeat.wrap(1.25), 0.25));
+        assert!(close(WrapMode::Repeat.wrap(-0.25), 0.75));
+        assert!(close(WrapMode::Repeat.wrap(-3.), 0.));
+    }
+
+    #[test]
+    fn mirror_reflects_every_other_tile() {
+        assert!(close(WrapMode::Mirror.wrap(0.25), 0.25));
+        assert!(close(WrapMode::Mirror.wrap(1.25), 0.75));
+        assert!(close(WrapMode::Mirror.wrap(2.25), 0.25));
+        assert!(close(WrapMode::Mirror.wrap(-0.25), 0.25));
+    }
+
+    #[test]
+    fn clamp_and_border() {
+        assert!(close(WrapMode::Clamp.wrap(-0.5), 0.));
+        assert!(close(WrapMode::Clamp.wrap(1.5), 1.));
+
+        let border = WrapMode::Border(Vec3::splat(1.));
+        assert!(close(border.wrap(0.5), 0.5));
+        assert!(border.wrap(-0.1).is_none());
+        assert!(border.wrap(1.1).is_none());
+    }
+
+    #[test]
+    fn texel_indices() {
+        assert_eq!(WrapMode::Repeat.wrap_index(-1, 4), 3);
+        assert_eq!(WrapMode::Repeat.wrap_index(4, 4), 0);
+        assert_eq!(WrapMode::Mirror.wrap_index(-1, 4), 0);
+        assert_eq!(WrapMode::Mirror.wrap_index(4, 4), 3);
+        assert_eq!(WrapMode::Mirror.wrap_index(8, 4), 0);
+        assert_eq!(WrapMode::Clamp.wrap_index(-1, 4), 0);
+        assert_eq!(WrapMode::Clamp.wrap_index(4, 4), 3);
+    }
+
+    #[test]
+    fn repeated_image_filters_across_the_seam() {
+        let image = Image::from_f32(vec![0., 1.], 2, 1, 1)
+            .filter(FilterMode::Bilinear)
+            .wrap(WrapMode::Repeat);
+        let tiled = UvTransform::new(image).scale(2., 1.);
+
+        let seam = tiled.value(0.5, 0.5, Vec3::splat(0.));
+        assert!((seam.x() - 0.5).abs() < 1e-5);
+    }
+}
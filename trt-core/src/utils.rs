@@ -1,17 +1,68 @@
 use crate::prelude::{Hit, Ray, Vec3};
+use crate::ray::RayCone;
+use crate::material::nested::MediumStack;
+use crate::atmosphere::Atmosphere;
 
 pub use rand::{Rng, thread_rng, seq::SliceRandom, distributions::Distribution};
 
-pub fn compute_color(mut ray: Ray, world: &impl Hit, ambiant_color: Vec3, max_depth: usize) -> Vec3 {
+pub fn compute_color(
+    mut ray: Ray,
+    world: &impl Hit,
+    atmosphere: Option<&Atmosphere>,
+    ambiant_color: Vec3,
+    max_depth: usize,
+    pixel_spread: f32,
+) -> Vec3 {
     let mut components = Vec::with_capacity(max_depth);
+    let mut media = MediumStack::default();
+    let mut path_length = 0.;
+    ray.cone = RayCone { width: 0., spread: pixel_spread };
 
     for _depth in 0..max_depth {
-        if let Some(rec) = world.hit(&ray, 0.001, std::f32::MAX) {
-            let emitted = rec.mat.emitted(rec.u, rec.v, rec.p);
+        let rec = world.hit(&ray, 0.001, std::f32::MAX);
+        // Nested media already attenuate with their own absorption.
+        let rec = match atmosphere {
+            Some(atmosphere) if media.is_empty() => {
+                let t_max = rec.as_ref().map_or(std::f32::MAX, |rec| rec.t);
+                atmosphere.hit(&ray, 0.001, t_max).or(rec)
+            },
+            _ => rec,
+        };
 
-            if let Some((scattered, attenuation)) = rec.mat.scatter(&ray, &rec) {
-                components.push((emitted, attenuation));
-                ray = scattered;
+        if let Some(rec) = rec {
+            path_length += rec.t * ray.direction.len();
+
+            let transmittance = media.transmittance(rec.t * ray.direction.len());
+            let emitted = transmittance * rec.mat.emitted(&rec);
+
+            let scattered = match rec.mat.interface().map(|interface| interface.bound_to(rec.boundary)) {
+                Some(interface) if !media.is_true_intersection(&interface) => {
+                    media.cross(interface);
+                    let passed_through = Ray {
+                        origin: rec.p,
+                        direction: ray.direction,
+                        time: ray.time,
+                        cone: ray.cone,
+                    };
+                    Some((passed_through, Vec3::splat(1.)))
+                },
+                Some(interface) => {
+                    let outer_ref_idx = media.outer_ref_idx(&interface);
+                    let scattered = rec.mat.scatter_nested(&ray, &rec, outer_ref_idx);
+                    if let Some((scattered_ray, _)) = &scattered {
+                        let crossed = Vec3::dot(ray.direction, rec.normal) * Vec3::dot(scattered_ray.direction, rec.normal) > 0.;
+                        if crossed {
+                            media.cross(interface)
+                        }
+                    }
+                    scattered
+                },
+                None => rec.mat.scatter(&ray, &rec),
+            };
+
+            if let Some((scattered, attenuation)) = scattered {
+                components.push((emitted, transmittance * attenuation));
+                ray = Ray { cone: RayCone { width: pixel_spread * path_length, spread: pixel_spread }, ..scattered };
             } else {
                 return components.into_iter().rev()
                     .fold(emitted, |r, (e, a)| r * a + e)
@@ -30,6 +81,16 @@ pub fn random_in_unit_sphere(mut rng: impl Rng) -> Vec3 {
     Vec3::new(x, y, z)
 }
 
+pub fn random_cosine_direction(mut rng: impl Rng) -> Vec3 {
+    use std::f32::consts::PI;
+    let r1 = rng.gen::<f32>();
+    let r2 = rng.gen::<f32>();
+    let phi = 2. * PI * r1;
+    let r2_sqrt = r2.sqrt();
+
+    Vec3::new(phi.cos() * r2_sqrt, phi.sin() * r2_sqrt, (1. - r2).sqrt())
+}
+
 pub fn random_in_unit_disk(mut rng: impl Rng) -> Vec3 {
     let [x, y]: [f32; 2] = rand_distr::UnitDisc.sample(&mut rng);
     Vec3::new(x, y, 0)
@@ -58,19 +119,62 @@ pub fn schlick(cosine: f32, ref_idx: f32) -> f32 {
     r0 + (1. - r0) * (1. - cosine).powi(5)
 }
 
+pub struct Onb {
+    pub u: Vec3,
+    pub v: Vec3,
+    pub w: Vec3,
+}
+
+impl Onb {
+    pub fn from_w(normal: Vec3) -> Self {
+        let w = normal.unit();
+        let helper = if w.x().abs() > 0.9 { Vec3::new(0, 1, 0) } else { Vec3::new(1, 0, 0) };
+        let v = Vec3::cross(w, helper).unit();
+        let u = Vec3::cross(w, v);
+        Self { u, v, w }
+    }
+
+    pub fn local(&self, a: Vec3) -> Vec3 {
+        a.x() * self.u + a.y() * self.v + a.z() * self.w
+    }
+}
+
+pub fn face_normal(direction: Vec3, outward_normal: Vec3) -> (bool, Vec3) {
+    let front_face = Vec3::dot(direction, outward_normal) < 0.;
+    (front_face, if front_face { outward_normal } else { -outward_normal })
+}
+
+pub fn tangent_frame(normal: Vec3, dpdu: Vec3) -> (Vec3, Vec3) {
+    let projected = dpdu - Vec3::dot(normal, dpdu) * normal;
+    let tangent = if projected.len() > 1e-6 { projected.unit() } else { Onb::from_w(normal).u };
+    (tangent, Vec3::cross(normal, tangent))
+}
+
+/// UVs of a point `p` on the unit sphere: `u` wraps once around the y axis,
+/// `v` goes from 0 at the south pole to 1 at the north pole.
 pub fn sphere_uv(p: Vec3) -> (f32, f32) {
     use std::f32::consts::PI;
     let phi = f32::atan2(p.z(), p.x());
-    let theta = p.y().asin();
+    let theta = p.y().max(-1.).min(1.).asin();
     let u = 1. - (phi + PI) / (2. * PI);
     let v = (theta + PI / 2.) / PI;
     (u, v)
 }
 
-pub fn cylinder_uv(p: Vec3) -> (f32, f32) {
+/// UVs of a point on the side of a cylinder, relative to its base: `u` wraps
+/// once around the y axis like `sphere_uv`, `v` goes from 0 at the base to 1 at the top.
+pub fn cylinder_uv(local: Vec3, height: f32) -> (f32, f32) {
     use std::f32::consts::PI;
-    let phi = f32::atan2(p.z(), p.x());
+    let phi = f32::atan2(local.z(), local.x());
     let u = 1. - (phi + PI) / (2. * PI);
-    let v = p.y() % 1.;
+    let v = (local.y() / height).max(0.).min(1.);
     (u, v)
 }
+
+/// UVs of a point on a disk of `radius` in the xz plane, relative to its center:
+/// the disk is inscribed in the unit square, `u` following x and `v` following z.
+pub fn disk_uv(local: Vec3, radius: f32) -> (f32, f32) {
+    let u = 0.5 + local.x() / (2. * radius);
+    let v = 0.5 + local.z() / (2. * radius);
+    (u.max(0.).min(1.), v.max(0.).min(1.))
+}
@@ -53,6 +53,10 @@ impl Vec3 {
         Self([self.x().sqrt(), self.y().sqrt(), self.z().sqrt(), 0.])
     }
 
+    pub fn exp(self) -> Vec3 {
+        Self([self.x().exp(), self.y().exp(), self.z().exp(), 0.])
+    }
+
     pub fn dot(self, other: Vec3) -> f32 {
         self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
     }
@@ -53,6 +53,10 @@ impl Vec3 {
         Self(self.0.sqrt())
     }
 
+    pub fn exp(self) -> Vec3 {
+        Self(f32x4::new(self.x().exp(), self.y().exp(), self.z().exp(), 0.))
+    }
+
     pub fn dot(self, other: Vec3) -> f32 {
         (self.0 * other.0).sum()
     }
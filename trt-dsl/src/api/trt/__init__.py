@@ -9,11 +9,19 @@ def render(world, **config):
     DEFAULT_SPX = 50
     DEFAULT_RPS = 25
     DEFAULT_AMBIANT = (0, 0, 0)
+    DEFAULT_ATMOSPHERE = {
+        'density': 0,
+        'albedo': (1, 1, 1),
+        'anisotropy': 0,
+        'radius': float('inf'),
+    }
     DEFAULT_CAMERA = {
         'look_at': (0, 0, 0),
         'look_from': (0, 0, 0)
     }
 
+    atmosphere = dict(DEFAULT_ATMOSPHERE, **config.get('atmosphere', {}))
+
     config = {
         'world': world,
         'width': config.get('width', DEFAULT_WIDTH),
@@ -21,6 +29,10 @@ def render(world, **config):
         'samples_per_px': config.get('samples_per_px', DEFAULT_SPX),
         'rays_per_sample': config.get('rays_per_sample', DEFAULT_RPS),
         'ambiant_color': config.get('ambiant_color', DEFAULT_AMBIANT),
+        'atmosphere_density': float(atmosphere['density']),
+        'atmosphere_albedo': atmosphere['albedo'],
+        'atmosphere_anisotropy': float(atmosphere['anisotropy']),
+        'atmosphere_radius': float(atmosphere['radius']),
         'camera': _camera(**config.get('camera', DEFAULT_CAMERA))
     }
 
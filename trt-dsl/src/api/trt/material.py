@@ -3,19 +3,178 @@ import _trt
 def matte(color):
     return _trt.Material.matte(color)
 
+def rough_matte(color, roughness):
+    return _trt.Material.oren_nayar(_texture(color), _texture(roughness))
+
 def metallic(color, fuzz=0):
     return _trt.Material.metallic_fuzzed(color, float(fuzz))
 
-def dielectric(ref_idx):
-    return _trt.Material.dielectric(ref_idx)
+def conductor(eta, k, roughness=0):
+    return _trt.Material.conductor(eta, k, _texture(roughness))
+
+def gold(roughness=0):
+    return _trt.Material.conductor_preset('gold', _texture(roughness))
+
+def copper(roughness=0):
+    return _trt.Material.conductor_preset('copper', _texture(roughness))
+
+def silver(roughness=0):
+    return _trt.Material.conductor_preset('silver', _texture(roughness))
+
+def aluminium(roughness=0):
+    return _trt.Material.conductor_preset('aluminium', _texture(roughness))
+
+def anisotropic(eta, k, roughness_x, roughness_y, rotation=0):
+    return _trt.Material.anisotropic(eta, k, _texture(roughness_x), _texture(roughness_y), float(rotation))
+
+def brushed_aluminium(roughness_x=0.4, roughness_y=0.05, rotation=0):
+    return _trt.Material.anisotropic_preset(
+        'aluminium', _texture(roughness_x), _texture(roughness_y), float(rotation)
+    )
+
+def thin_film_conductor(eta, k, film_ior, thickness, roughness=0):
+    return _trt.Material.thin_film_conductor(eta, k, _texture(roughness), float(film_ior), _texture(thickness))
+
+def thin_film_dielectric(ref_idx, film_ior, thickness):
+    return _trt.Material.thin_film_dielectric(float(ref_idx), float(film_ior), _texture(thickness))
+
+def soap_bubble(thickness=0.4, film_ior=1.33):
+    return thin_film_dielectric(1.0, film_ior, thickness)
+
+def dielectric(ref_idx, priority=0):
+    return _trt.Material.dielectric(ref_idx, int(priority))
+
+def colored_glass(ref_idx, absorption, priority=0):
+    return _trt.Material.colored_glass(float(ref_idx), absorption, int(priority))
+
+def rough_dielectric(ref_idx, roughness):
+    return _trt.Material.rough_dielectric(float(ref_idx), _texture(roughness))
 
 def diffuse_color(color):
     return _trt.Material.diffuse_color(color)
 
-def image(url, cors_proxy=False):
+def mix(a, b, factor):
+    return _trt.Material.mix(a, b, _texture(factor))
+
+def coated(base, ref_idx=1.5):
+    return _trt.Material.coated(base, float(ref_idx))
+
+def two_sided(front, back):
+    return _trt.Material.two_sided(front, back)
+
+def bump(material, height, strength=1):
+    return _trt.Material.bump(material, _texture(height), float(strength))
+
+def normal_map(material, normals, strength=1):
+    return _trt.Material.normal_map(material, _texture(normals), float(strength))
+
+def image(url, cors_proxy=False, color_space='srgb'):
     if cors_proxy:
         url = f'https://cors-anywhere.herokuapp.com/{url}'
-    return _trt.Material.image(url)
+    return _trt.Material.image(url, color_space)
 
 def checker(c1, c2, repeat_frequency):
     return _trt.Material.checker(c1, c2, float(repeat_frequency))
+
+def _texture(value):
+    if isinstance(value, _trt.Texture):
+        return value
+    if isinstance(value, tuple):
+        return _trt.Texture.constant(value)
+    return _trt.Texture.constant((float(value),) * 3)
+
+def constant_texture(color):
+    return _trt.Texture.constant(color)
+
+def checker_texture(odd, even, repeat_frequency):
+    return _trt.Texture.checker(_texture(odd), _texture(even), float(repeat_frequency))
+
+def uv_checker_texture(odd, even, repeat=(2, 2)):
+    return _trt.Texture.uv_checker(_texture(odd), _texture(even), tuple(float(x) for x in repeat))
+
+def transform_texture(texture, scale=(1, 1), offset=(0, 0), rotation=0, wrap='repeat', border=(0, 0, 0)):
+    return _texture(texture).transform(
+        tuple(float(x) for x in scale),
+        tuple(float(x) for x in offset),
+        float(rotation),
+        wrap,
+        tuple(float(x) for x in border),
+    )
+
+def noise_texture(scale, seed=0):
+    return _trt.Texture.noise(float(scale), int(seed))
+
+def perlin_noise(seed=0):
+    return _trt.Noise.perlin(int(seed))
+
+def simplex_noise(seed=0):
+    return _trt.Noise.simplex(int(seed))
+
+def worley_noise(seed=0):
+    return _trt.Noise.worley(int(seed))
+
+def fbm(noise, octaves=6, lacunarity=2, gain=0.5):
+    return noise.fbm(int(octaves), float(lacunarity), float(gain))
+
+def ridged(noise, octaves=6, lacunarity=2, gain=0.5):
+    return noise.ridged(int(octaves), float(lacunarity), float(gain))
+
+def domain_warp(noise, warp, strength=1):
+    return noise.warp(warp, float(strength))
+
+def procedural_texture(noise, scale=1):
+    return _trt.Texture.from_noise(noise, float(scale))
+
+def image_texture(url, cors_proxy=False, filter='nearest', color_space='srgb', wrap='clamp'):
+    if cors_proxy:
+        url = f'https://cors-anywhere.herokuapp.com/{url}'
+    return _trt.Texture.image(url, filter, color_space, wrap)
+
+def image_alpha_texture(url, cors_proxy=False):
+    if cors_proxy:
+        url = f'https://cors-anywhere.herokuapp.com/{url}'
+    return _trt.Texture.image_alpha(url)
+
+def mix_texture(a, b, factor):
+    return _texture(a).mix(_texture(b), _texture(factor))
+
+def multiply_texture(a, b):
+    return _texture(a).multiply(_texture(b))
+
+def add_texture(a, b):
+    return _texture(a).add(_texture(b))
+
+def ramp_texture(input, stops):
+    return _texture(input).ramp([(float(pos), tuple(float(c) for c in color)) for pos, color in stops])
+
+def scalar_map_texture(input, low=(0, 0, 0), high=(1, 1, 1)):
+    return _texture(input).scalar_map(tuple(float(c) for c in low), tuple(float(c) for c in high))
+
+def hsv_texture(input, hue=0, saturation=1, value=1):
+    return _texture(input).hsv(float(hue), float(saturation), float(value))
+
+def invert_texture(input):
+    return _texture(input).invert()
+
+def clamp_texture(input, min=(0, 0, 0), max=(1, 1, 1)):
+    return _texture(input).clamp(tuple(float(c) for c in min), tuple(float(c) for c in max))
+
+def triplanar_texture(texture, scale=1, sharpness=4, object_space=False):
+    return _texture(texture).triplanar(float(scale), float(sharpness), bool(object_space))
+
+def blackbody_texture(temperature, intensity=1):
+    return _trt.Texture.blackbody(float(temperature), float(intensity))
+
+def blackbody_noise_texture(scale, max_temperature, intensity=1):
+    return _trt.Texture.blackbody_noise(float(scale), float(max_temperature), float(intensity))
+
+def principled(base_color, metallic=0, roughness=0.5, specular=0.5, sheen=0, clearcoat=0, transmission=0):
+    return _trt.Material.principled(
+        _texture(base_color),
+        _texture(metallic),
+        _texture(roughness),
+        _texture(specular),
+        _texture(sheen),
+        _texture(clearcoat),
+        _texture(transmission),
+    )
@@ -1,13 +1,16 @@
 use crate::{future::PyFuture, prelude::*};
-use super::{shape::SharedHit, vec3::PyVec3};
+use super::{shape::SharedHit, texture::{fetch_image, parse_color_space, PyTexture}, vec3::PyVec3};
 
 use trt_core::{
-    material::{Dielectric, Diffuse, Lambertian, Metal},
+    density::VoxelError,
+    material::{Anisotropic, Bump, Coated, Conductor, Dielectric, Diffuse, Lambertian, Metal, Mix, NormalMap, OrenNayar, Principled, RoughDielectric, TwoSided},
     prelude::*,
-    texture::{Checker, Image, Constant},
+    texture::{Checker, Constant},
 };
 
-use rpy::obj::objstr::PyStringRef;
+use rpy::{function::OptionalArg, obj::objstr::PyStringRef};
+
+use futures::prelude::*;
 
 use std::fmt;
 
@@ -15,13 +18,19 @@ use std::fmt;
 pub enum MaterialError {
     ImageFetch { err: reqwest::Error, url: String },
     ImageLoad { err: image::ImageError, url: String },
+    VolumeFetch { err: reqwest::Error, url: String },
+    VolumeLoad { err: VoxelError, url: String },
+    UnboundedVolume,
 }
 
 impl fmt::Display for MaterialError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             MaterialError::ImageFetch { err, url } => write!(f, "Error fetching \"{}\": {}", url, err),
-            MaterialError::ImageLoad { err, url } => write!(f, "Unsupported image format for: \"{}\": {}", url, err)
+            MaterialError::ImageLoad { err, url } => write!(f, "Unsupported image format for: \"{}\": {}", url, err),
+            MaterialError::VolumeFetch { err, url } => write!(f, "Error fetching \"{}\": {}", url, err),
+            MaterialError::VolumeLoad { err, url } => write!(f, "Invalid voxel data in \"{}\": {}", url, err),
+            MaterialError::UnboundedVolume => write!(f, "Voxel media need a bounded shape"),
         }
     }
 }
@@ -58,11 +67,35 @@ impl TryFromObject for PyMaterial {
     }
 }
 
+#[derive(Debug, rpy::FromArgs)]
+struct PyPrincipledArgs {
+    base_color: PyTexture,
+    metallic: PyTexture,
+    roughness: PyTexture,
+    specular: PyTexture,
+    sheen: PyTexture,
+    clearcoat: PyTexture,
+    transmission: PyTexture,
+}
+
 #[rpy::pyimpl]
 impl PyMaterial {
     #[pyclassmethod]
-    fn dielectric(_cls: PyClassRef, ref_idx: f32) -> Self {
-        Self::new(Dielectric::new(ref_idx))
+    fn dielectric(_cls: PyClassRef, ref_idx: f32, priority: u32) -> Self {
+        Self::new(Dielectric::new(ref_idx).priority(priority))
+    }
+
+    #[pyclassmethod]
+    fn colored_glass(_cls: PyClassRef, ref_idx: f32, absorption: PyVec3, priority: u32) -> Self {
+        Self::new(Dielectric::absorbing(ref_idx, absorption.into_vec()).priority(priority))
+    }
+
+    #[pyclassmethod]
+    fn rough_dielectric(_cls: PyClassRef, ref_idx: f32, roughness: PyTexture) -> Self {
+        let dielectric = roughness.shared()
+            .map_ok(move |roughness| Rc::new(RoughDielectric::new(ref_idx, roughness)) as _);
+
+        Self(PyFuture::new(dielectric))
     }
 
     #[pyclassmethod]
@@ -75,11 +108,149 @@ impl PyMaterial {
         Self::new(Lambertian::colored(color.into_vec()))
     }
 
+    #[pyclassmethod]
+    fn oren_nayar(_cls: PyClassRef, albedo: PyTexture, roughness: PyTexture) -> Self {
+        let oren_nayar = future::try_join(albedo.shared(), roughness.shared())
+            .map_ok(|(albedo, roughness)| Rc::new(OrenNayar::new(albedo, roughness)) as _);
+
+        Self(PyFuture::new(oren_nayar))
+    }
+
     #[pyclassmethod]
     fn metallic_fuzzed(_cls: PyClassRef, albedo: PyVec3, fuzz: f32) -> Self {
         Self::new(Metal::new(albedo.into_vec(), fuzz))
     }
 
+    #[pyclassmethod]
+    fn conductor(_cls: PyClassRef, eta: PyVec3, k: PyVec3, roughness: PyTexture) -> Self {
+        let conductor = roughness.shared()
+            .map_ok(move |roughness| {
+                Rc::new(Conductor::new(eta.into_vec(), k.into_vec(), roughness)) as _
+            });
+
+        Self(PyFuture::new(conductor))
+    }
+
+    #[pyclassmethod]
+    fn anisotropic(
+        _cls: PyClassRef,
+        eta: PyVec3,
+        k: PyVec3,
+        roughness_x: PyTexture,
+        roughness_y: PyTexture,
+        rotation: f32,
+    ) -> Self {
+        let anisotropic = future::try_join(roughness_x.shared(), roughness_y.shared())
+            .map_ok(move |(roughness_x, roughness_y)| {
+                let anisotropic = Anisotropic::new(eta.into_vec(), k.into_vec(), roughness_x, roughness_y)
+                    .rotation(rotation);
+                Rc::new(anisotropic) as _
+            });
+
+        Self(PyFuture::new(anisotropic))
+    }
+
+    #[pyclassmethod]
+    fn thin_film_conductor(
+        _cls: PyClassRef,
+        eta: PyVec3,
+        k: PyVec3,
+        roughness: PyTexture,
+        film_ior: f32,
+        thickness: PyTexture,
+    ) -> Self {
+        let conductor = future::try_join(roughness.shared(), thickness.shared())
+            .map_ok(move |(roughness, thickness)| {
+                let conductor = Conductor::new(eta.into_vec(), k.into_vec(), roughness)
+                    .thin_film(film_ior, thickness);
+                Rc::new(conductor) as _
+            });
+
+        Self(PyFuture::new(conductor))
+    }
+
+    #[pyclassmethod]
+    fn thin_film_dielectric(_cls: PyClassRef, ref_idx: f32, film_ior: f32, thickness: PyTexture) -> Self {
+        let dielectric = thickness.shared()
+            .map_ok(move |thickness| Rc::new(Dielectric::new(ref_idx).thin_film(film_ior, thickness)) as _);
+
+        Self(PyFuture::new(dielectric))
+    }
+
+    #[pyclassmethod]
+    fn conductor_preset(_cls: PyClassRef, name: PyStringRef, roughness: PyTexture, vm: &VirtualMachine) -> PyResult<Self> {
+        let preset: fn(Rc<dyn Texture>) -> Conductor<Rc<dyn Texture>> = match name.as_str() {
+            "gold" => Conductor::gold,
+            "copper" => Conductor::copper,
+            "silver" => Conductor::silver,
+            "aluminium" => Conductor::aluminium,
+            other => return Err(vm.new_value_error(format!("Unknown conductor preset: '{}'", other))),
+        };
+
+        let conductor = roughness.shared()
+            .map_ok(move |roughness| Rc::new(preset(roughness)) as _);
+
+        Ok(Self(PyFuture::new(conductor)))
+    }
+
+    #[pyclassmethod]
+    fn anisotropic_preset(
+        _cls: PyClassRef,
+        name: PyStringRef,
+        roughness_x: PyTexture,
+        roughness_y: PyTexture,
+        rotation: f32,
+        vm: &VirtualMachine,
+    ) -> PyResult<Self> {
+        type Preset = fn(Rc<dyn Texture>, Rc<dyn Texture>) -> Anisotropic<Rc<dyn Texture>, Rc<dyn Texture>>;
+        let preset: Preset = match name.as_str() {
+            "aluminium" => Anisotropic::aluminium,
+            other => return Err(vm.new_value_error(format!("Unknown anisotropic preset: '{}'", other))),
+        };
+
+        let anisotropic = future::try_join(roughness_x.shared(), roughness_y.shared())
+            .map_ok(move |(roughness_x, roughness_y)| Rc::new(preset(roughness_x, roughness_y).rotation(rotation)) as _);
+
+        Ok(Self(PyFuture::new(anisotropic)))
+    }
+
+    #[pyclassmethod]
+    fn mix(_cls: PyClassRef, a: PyMaterial, b: PyMaterial, factor: PyTexture) -> Self {
+        let mix = future::try_join3(a.0.shared(), b.0.shared(), factor.shared())
+            .map_ok(|(a, b, factor)| Rc::new(Mix::new(a, b, factor)) as _);
+
+        Self(PyFuture::new(mix))
+    }
+
+    #[pyclassmethod]
+    fn coated(_cls: PyClassRef, base: PyMaterial, ref_idx: f32) -> Self {
+        Self(base.0.map(move |base| Ok(Rc::new(Coated::new(base?, ref_idx)) as _)))
+    }
+
+    #[pyclassmethod]
+    fn two_sided(_cls: PyClassRef, front: PyMaterial, back: PyMaterial) -> Self {
+        let two_sided = future::try_join(front.0.shared(), back.0.shared())
+            .map_ok(|(front, back)| Rc::new(TwoSided::new(front, back)) as _);
+
+        Self(PyFuture::new(two_sided))
+    }
+
+    #[pyclassmethod]
+    fn bump(_cls: PyClassRef, material: PyMaterial, height: PyTexture, strength: f32) -> Self {
+        let bump = future::try_join(material.0.shared(), height.shared())
+            .map_ok(move |(material, height)| Rc::new(Bump::new(material, height, strength)) as _);
+
+        Self(PyFuture::new(bump))
+    }
+
+    #[pyclassmethod]
+    fn normal_map(_cls: PyClassRef, material: PyMaterial, normals: PyTexture, strength: f32) -> Self {
+        let normal_map = future::try_join(material.0.shared(), normals.shared())
+            .map_ok(move |(material, normals)| Rc::new(NormalMap::new(material, normals, strength)) as _);
+
+        Self(PyFuture::new(normal_map))
+    }
+
     #[pyclassmethod]
     fn checker(_cls: PyClassRef, col1: PyVec3, col2: PyVec3, repeat_frequency: f32) -> Self {
         let tx1 = Constant::new(col1.into_vec());
@@ -90,27 +261,44 @@ impl PyMaterial {
     }
 
     #[pyclassmethod]
-    fn image(_cls: PyClassRef, url: PyStringRef) -> Self {
-        Self(PyFuture::new(async move {
-            let url = url.as_str();
+    fn image(_cls: PyClassRef, url: PyStringRef, color_space: OptionalArg<PyStringRef>, vm: &VirtualMachine) -> PyResult<Self> {
+        let color_space = parse_color_space(color_space, vm)?;
+
+        Ok(Self(PyFuture::new(async move {
+            let img = fetch_image(url.as_str(), color_space).await?;
+
+            Ok(Rc::new(Lambertian::new(img)) as _)
+        })))
+    }
 
-            let resp = reqwest::get(url)
-                .await
-                .map_err(|err| Rc::new(MaterialError::ImageFetch { err, url: url.to_owned() }))?;
+    #[pyclassmethod]
+    fn principled(_cls: PyClassRef, args: PyPrincipledArgs) -> Self {
+        let textures = vec![
+            args.base_color,
+            args.metallic,
+            args.roughness,
+            args.specular,
+            args.sheen,
+            args.clearcoat,
+            args.transmission,
+        ];
 
-            let bytes = resp
-                .bytes()
-                .await
-                .map_err(|err| Rc::new(MaterialError::ImageFetch { err, url: url.to_owned() }))?;
+        let principled = future::try_join_all(textures.iter().map(PyTexture::shared))
+            .map_ok(|textures| {
+                let mut textures = textures.into_iter();
+                let mut next = || textures.next().expect("Missing principled parameter");
 
-            let raw_img = image::load_from_memory(&bytes)
-                .map_err(|err| Rc::new(MaterialError::ImageLoad { err, url: url.to_owned() }))?
-                .into_rgb();
+                let principled = Principled::new(next())
+                    .metallic(next())
+                    .roughness(next())
+                    .specular(next())
+                    .sheen(next())
+                    .clearcoat(next())
+                    .transmission(next());
 
-            let (width, height) = raw_img.dimensions();
-            let img = Image::load(raw_img.into_vec(), width as _, height as _);
+                Rc::new(principled) as _
+            });
 
-            Ok(Rc::new(Lambertian::new(img)) as _)
-        }))
+        Self(PyFuture::new(principled))
     }
 }
@@ -28,7 +28,9 @@ mod vec3;
 mod float;
 mod scene;
 mod material;
+mod noise;
 mod shape;
+mod texture;
 
 pub use scene::{DynScene, DynSceneResult};
 
@@ -88,6 +90,8 @@ impl<'vm> SceneInjector<'vm> {
 fn make_trt_module(vm: &VirtualMachine) -> PyObjectRef {
     rpy::py_module!(vm, TRT_INTERNAL_MODULE_NAME, {
         "Material" => material::PyMaterial::make_class(&vm.ctx),
+        "Texture" => texture::PyTexture::make_class(&vm.ctx),
+        "Noise" => noise::PyNoise::make_class(&vm.ctx),
         "Shape" => shape::PyShape::make_class(&vm.ctx),
         "Scene" => scene::PyScene::make_class(&vm.ctx),
         "Camera" => camera::PyCamera::make_class(&vm.ctx),
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use super::float::FloatLike;
+
+use trt_core::noise::{DomainWarp, Fbm, NoiseFn, Perlin, Ridged, Simplex, Worley};
+
+trt_py_class! { "Noise", PyNoise,
+    #[derive(Clone)]
+    pub struct PyNoise(Rc<dyn NoiseFn>);
+}
+
+impl PyNoise {
+    pub fn new<N: NoiseFn + 'static>(noise: N) -> Self {
+        Self(Rc::new(noise))
+    }
+
+    pub fn noise_fn(&self) -> Rc<dyn NoiseFn> {
+        self.0.clone()
+    }
+}
+
+impl TryFromObject for PyNoise {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        let noise: PyRef<Self> = obj.try_into_ref(vm)?;
+
+        Ok((*noise).clone())
+    }
+}
+
+#[rpy::pyimpl]
+impl PyNoise {
+    #[pyclassmethod]
+    fn perlin(_cls: PyClassRef, seed: u64) -> Self {
+        Self::new(Perlin::seeded(seed))
+    }
+
+    #[pyclassmethod]
+    fn simplex(_cls: PyClassRef, seed: u64) -> Self {
+        Self::new(Simplex::seeded(seed))
+    }
+
+    #[pyclassmethod]
+    fn worley(_cls: PyClassRef, seed: u64) -> Self {
+        Self::new(Worley::seeded(seed))
+    }
+
+    #[pymethod]
+    fn fbm(&self, octaves: usize, lacunarity: FloatLike, gain: FloatLike) -> Self {
+        let fbm = Fbm::new(self.noise_fn(), octaves)
+            .lacunarity(lacunarity.as_f32())
+            .gain(gain.as_f32());
+
+        Self::new(fbm)
+    }
+
+    #[pymethod]
+    fn ridged(&self, octaves: usize, lacunarity: FloatLike, gain: FloatLike) -> Self {
+        let ridged = Ridged::new(self.noise_fn(), octaves)
+            .lacunarity(lacunarity.as_f32())
+            .gain(gain.as_f32());
+
+        Self::new(ridged)
+    }
+
+    #[pymethod]
+    fn warp(&self, warp: PyNoise, strength: FloatLike) -> Self {
+        Self::new(DomainWarp::new(self.noise_fn(), warp.noise_fn(), strength.as_f32()))
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{future::PyFuture, prelude::*};
 use super::{camera::PyCamera, material::MaterialError, shape::PyShape, vec3::PyVec3};
 
-use trt_core::{hit::HitList, prelude::*, scene::Scene};
+use trt_core::{atmosphere::Atmosphere, hit::HitList, prelude::*, scene::Scene};
 
 use futures::prelude::*;
 
@@ -21,6 +21,10 @@ struct PySceneArgs {
     samples_per_px: u32,
     rays_per_sample: u32,
     ambiant_color: PyVec3,
+    atmosphere_density: f32,
+    atmosphere_albedo: PyVec3,
+    atmosphere_anisotropy: f32,
+    atmosphere_radius: f32,
 }
 
 #[rpy::pyimpl]
@@ -50,6 +54,14 @@ impl PyScene {
         let samples_per_px = args.samples_per_px;
         let rays_per_sample = args.rays_per_sample;
         let ambiant_color = args.ambiant_color.into_vec();
+        let atmosphere = if args.atmosphere_density > 0. {
+            let atmosphere = Atmosphere::new(args.atmosphere_density, args.atmosphere_albedo.into_vec())
+                .anisotropy(args.atmosphere_anisotropy)
+                .bounded(Vec3::splat(0.), args.atmosphere_radius);
+            Some(atmosphere)
+        } else {
+            None
+        };
 
         let scene_future = future::try_join_all(world_futures).map_ok(move |world| {
             let scene = Scene {
@@ -60,6 +72,7 @@ impl PyScene {
                 samples_per_px,
                 rays_per_sample,
                 ambiant_color,
+                atmosphere,
             };
             Rc::new(scene)
         });
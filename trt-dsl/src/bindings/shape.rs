@@ -3,14 +3,18 @@ use crate::future::PyFuture;
 use super::{
     float::FloatLike,
     material::{MaterialError, PyMaterial},
+    texture::PyTexture,
     vec3::PyVec3,
 };
 
 use trt_core::{
+    density::{HeightFalloff, NoiseDensity, VoxelGrid},
     hit::{RectBuilder, Sphere, HitBox, BVHNode, Cylinder},
     prelude::*,
 };
 
+use rpy::{function::OptionalArg, obj::objstr::PyStringRef};
+
 use futures::prelude::*;
 
 pub type SharedHit = PyFuture<Result<Rc<dyn Hit>, Rc<MaterialError>>>;
@@ -170,7 +174,105 @@ impl PyShape {
     }
 
     #[pymethod]
-    fn constant_medium(&self, density: FloatLike, color: PyVec3) -> Self {
-        self.map(move |h| h.constant_medium(density.as_f32(), color.into_vec()))
+    fn alpha_mask(&self, opacity: PyTexture, threshold: FloatLike) -> Self {
+        let masked = future::try_join(self.shared_hit().shared(), opacity.shared())
+            .map_ok(move |(hit, opacity)| Rc::new(hit.alpha_mask(opacity, threshold.as_f32())) as _);
+
+        Self(PyFuture::new(masked))
+    }
+
+    #[pymethod]
+    fn constant_medium(&self, density: FloatLike, color: PyVec3, g: OptionalArg<FloatLike>) -> Self {
+        match g.into_option() {
+            Some(g) => self.map(move |h| h.constant_medium_hg(density.as_f32(), color.into_vec(), g.as_f32())),
+            None => self.map(move |h| h.constant_medium(density.as_f32(), color.into_vec())),
+        }
+    }
+
+    #[pymethod]
+    fn emissive_medium(
+        &self,
+        density: FloatLike,
+        color: PyVec3,
+        emission: PyTexture,
+        g: OptionalArg<FloatLike>,
+    ) -> Self {
+        let g = g.into_option().map_or(0., FloatLike::as_f32);
+        let medium = future::try_join(self.shared_hit().shared(), emission.shared())
+            .map_ok(move |(hit, emission)| {
+                Rc::new(hit.emissive_medium(density.as_f32(), color.into_vec(), emission, g)) as _
+            });
+
+        Self(PyFuture::new(medium))
+    }
+
+    #[pymethod]
+    fn noise_medium(
+        &self,
+        density: FloatLike,
+        scale: FloatLike,
+        color: PyVec3,
+        g: OptionalArg<FloatLike>,
+        seed: OptionalArg<u64>,
+    ) -> Self {
+        let density = NoiseDensity::seeded(density.as_f32(), scale.as_f32(), seed.into_option().unwrap_or(0));
+        match g.into_option() {
+            Some(g) => self.map(move |h| h.heterogeneous_medium_hg(density, color.into_vec(), g.as_f32())),
+            None => self.map(move |h| h.heterogeneous_medium(density, color.into_vec())),
+        }
+    }
+
+    #[pymethod]
+    fn height_fog(
+        &self,
+        density: FloatLike,
+        falloff: FloatLike,
+        base_height: FloatLike,
+        color: PyVec3,
+        g: OptionalArg<FloatLike>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Self> {
+        if falloff.as_f32() < 0. {
+            return Err(vm.new_value_error("Height fog falloff must not be negative".to_owned()))
+        }
+
+        let density = HeightFalloff::new(density.as_f32(), falloff.as_f32(), base_height.as_f32());
+        Ok(match g.into_option() {
+            Some(g) => self.map(move |h| h.heterogeneous_medium_hg(density, color.into_vec(), g.as_f32())),
+            None => self.map(move |h| h.heterogeneous_medium(density, color.into_vec())),
+        })
+    }
+
+    #[pymethod]
+    fn voxel_medium(&self, url: PyStringRef, color: PyVec3, g: OptionalArg<FloatLike>) -> Self {
+        let g = g.into_option().map(FloatLike::as_f32);
+        let boundary = self.shared_hit();
+
+        let medium = async move {
+            let hit = boundary.shared().await?;
+            let bounds = hit.bounding_box(0., 1.).ok_or_else(|| Rc::new(MaterialError::UnboundedVolume))?;
+
+            let url = url.as_str();
+            let bytes = reqwest::get(url)
+                .and_then(|resp| resp.bytes())
+                .await
+                .map_err(|err| Rc::new(MaterialError::VolumeFetch { err, url: url.to_owned() }))?;
+
+            let grid = VoxelGrid::from_npy(&bytes, bounds)
+                .map_err(|err| Rc::new(MaterialError::VolumeLoad { err, url: url.to_owned() }))?;
+
+            let medium: Rc<dyn Hit> = match g {
+                Some(g) => Rc::new(hit.heterogeneous_medium_hg(grid, color.into_vec(), g)),
+                None => Rc::new(hit.heterogeneous_medium(grid, color.into_vec())),
+            };
+            Ok(medium)
+        };
+
+        Self(PyFuture::new(medium))
+    }
+
+    #[pymethod]
+    fn subsurface(&self, ref_idx: FloatLike, scattering: PyVec3, absorption: PyVec3) -> Self {
+        self.map(move |h| h.subsurface(ref_idx.as_f32(), scattering.into_vec(), absorption.into_vec()))
     }
 }
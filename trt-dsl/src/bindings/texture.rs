@@ -0,0 +1,277 @@
+use crate::{future::PyFuture, prelude::*};
+use super::{float::FloatLike, material::MaterialError, noise::PyNoise, vec3::PyVec3};
+
+use trt_core::{
+    density::NoiseDensity,
+    prelude::*,
+    texture::{
+        blackbody_color, AlphaChannel, Blackbody, Checker, ColorSpace, Constant, FilterMode, Image, Noise, NoiseTexture,
+        Triplanar, TriplanarSpace, UvChecker, UvTransform, WrapMode,
+    },
+};
+
+use rpy::{function::OptionalArg, obj::objstr::PyStringRef};
+
+use futures::prelude::*;
+
+pub type TextureResult = Result<Rc<dyn Texture>, Rc<MaterialError>>;
+
+trt_py_class! { "Texture", PyTexture,
+    #[derive(Clone)]
+    pub struct PyTexture(PyFuture<TextureResult>);
+}
+
+impl PyTexture {
+    pub fn new<Tx: Texture + 'static>(texture: Tx) -> Self {
+        Self(PyFuture::ready(Ok(Rc::new(texture))))
+    }
+
+    pub fn shared(&self) -> impl Future<Output = TextureResult> {
+        self.0.shared()
+    }
+}
+
+impl TryFromObject for PyTexture {
+    fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+        let texture: PyRef<Self> = obj.try_into_ref(vm)?;
+
+        Ok((*texture).clone())
+    }
+}
+
+pub fn parse_color_space(name: OptionalArg<PyStringRef>, vm: &VirtualMachine) -> PyResult<ColorSpace> {
+    match name.into_option() {
+        None => Ok(ColorSpace::Srgb),
+        Some(name) => match name.as_str() {
+            "srgb" => Ok(ColorSpace::Srgb),
+            "linear" => Ok(ColorSpace::Linear),
+            other => Err(vm.new_value_error(format!("Unknown color space: '{}'", other))),
+        },
+    }
+}
+
+fn parse_wrap(name: &str, border: Vec3, vm: &VirtualMachine) -> PyResult<WrapMode> {
+    match name {
+        "repeat" => Ok(WrapMode::Repeat),
+        "mirror" => Ok(WrapMode::Mirror),
+        "clamp" => Ok(WrapMode::Clamp),
+        "border" => Ok(WrapMode::Border(border)),
+        other => Err(vm.new_value_error(format!("Unknown wrap mode: '{}'", other))),
+    }
+}
+
+pub async fn fetch_image(url: &str, color_space: ColorSpace) -> Result<Image, Rc<MaterialError>> {
+    let resp = reqwest::get(url)
+        .await
+        .map_err(|err| Rc::new(MaterialError::ImageFetch { err, url: url.to_owned() }))?;
+
+    let bytes = resp
+        .bytes()
+        .await
+        .map_err(|err| Rc::new(MaterialError::ImageFetch { err, url: url.to_owned() }))?;
+
+    Image::decode(&bytes, color_space)
+        .map_err(|err| Rc::new(MaterialError::ImageLoad { err, url: url.to_owned() }))
+}
+
+#[rpy::pyimpl]
+impl PyTexture {
+    #[pyclassmethod]
+    fn constant(_cls: PyClassRef, color: PyVec3) -> Self {
+        Self::new(Constant::new(color.into_vec()))
+    }
+
+    #[pyclassmethod]
+    fn checker(_cls: PyClassRef, odd: PyTexture, even: PyTexture, repeat_frequency: f32) -> Self {
+        let checker = future::try_join(odd.shared(), even.shared())
+            .map_ok(move |(odd, even)| Rc::new(Checker::new(odd, even, repeat_frequency)) as _);
+
+        Self(PyFuture::new(checker))
+    }
+
+    #[pyclassmethod]
+    fn uv_checker(_cls: PyClassRef, odd: PyTexture, even: PyTexture, repeat: (f32, f32)) -> Self {
+        let checker = future::try_join(odd.shared(), even.shared())
+            .map_ok(move |(odd, even)| Rc::new(UvChecker::new(odd, even, repeat.0, repeat.1)) as _);
+
+        Self(PyFuture::new(checker))
+    }
+
+    #[pyclassmethod]
+    fn noise(_cls: PyClassRef, scale: f32, seed: OptionalArg<u64>) -> Self {
+        Self::new(Noise::seeded(scale, seed.into_option().unwrap_or(0)))
+    }
+
+    #[pyclassmethod]
+    fn from_noise(_cls: PyClassRef, noise: PyNoise, scale: f32) -> Self {
+        Self::new(NoiseTexture::new(noise.noise_fn(), scale))
+    }
+
+    #[pyclassmethod]
+    fn blackbody(_cls: PyClassRef, temperature: f32, intensity: f32) -> Self {
+        Self::new(Constant::new(intensity * blackbody_color(temperature)))
+    }
+
+    #[pyclassmethod]
+    fn blackbody_noise(_cls: PyClassRef, scale: f32, max_temperature: f32, intensity: f32) -> Self {
+        Self::new(Blackbody::new(NoiseDensity::new(1., scale), max_temperature, intensity))
+    }
+
+    #[pyclassmethod]
+    fn image(
+        _cls: PyClassRef,
+        url: PyStringRef,
+        filter: OptionalArg<PyStringRef>,
+        color_space: OptionalArg<PyStringRef>,
+        wrap: OptionalArg<PyStringRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Self> {
+        let filter = match filter.into_option() {
+            None => FilterMode::Nearest,
+            Some(filter) => match filter.as_str() {
+                "nearest" => FilterMode::Nearest,
+                "bilinear" => FilterMode::Bilinear,
+                "trilinear" => FilterMode::Trilinear,
+                other => return Err(vm.new_value_error(format!("Unknown filter mode: '{}'", other))),
+            },
+        };
+
+        let color_space = parse_color_space(color_space, vm)?;
+        let wrap = match wrap.into_option() {
+            None => WrapMode::Clamp,
+            Some(wrap) => parse_wrap(wrap.as_str(), Vec3::splat(0.), vm)?,
+        };
+
+        Ok(Self(PyFuture::new(async move {
+            let img = fetch_image(url.as_str(), color_space).await?;
+            Ok(Rc::new(img.filter(filter).wrap(wrap)) as _)
+        })))
+    }
+
+    #[pyclassmethod]
+    fn image_alpha(_cls: PyClassRef, url: PyStringRef) -> Self {
+        Self(PyFuture::new(async move {
+            let img = fetch_image(url.as_str(), ColorSpace::Linear).await?;
+            Ok(Rc::new(AlphaChannel::new(img)) as _)
+        }))
+    }
+
+    #[pymethod]
+    fn transform(
+        &self,
+        scale: (f32, f32),
+        offset: (f32, f32),
+        rotation: f32,
+        wrap: PyStringRef,
+        border: PyVec3,
+        vm: &VirtualMachine,
+    ) -> PyResult<Self> {
+        let wrap = parse_wrap(wrap.as_str(), border.into_vec(), vm)?;
+
+        let transformed = self.shared()
+            .map_ok(move |texture| {
+                let transformed = UvTransform::new(texture)
+                    .scale(scale.0, scale.1)
+                    .offset(offset.0, offset.1)
+                    .rotation(rotation)
+                    .wrap(wrap);
+                Rc::new(transformed) as _
+            });
+
+        Ok(Self(PyFuture::new(transformed)))
+    }
+
+    #[pymethod]
+    fn mix(&self, other: PyTexture, factor: PyTexture) -> Self {
+        let mixed = future::try_join3(self.shared(), other.shared(), factor.shared())
+            .map_ok(|(a, b, factor)| Rc::new(a.mix(b, factor)) as _);
+
+        Self(PyFuture::new(mixed))
+    }
+
+    #[pymethod]
+    fn multiply(&self, other: PyTexture) -> Self {
+        let product = future::try_join(self.shared(), other.shared())
+            .map_ok(|(a, b)| Rc::new(a.multiply(b)) as _);
+
+        Self(PyFuture::new(product))
+    }
+
+    #[pymethod]
+    fn add(&self, other: PyTexture) -> Self {
+        let sum = future::try_join(self.shared(), other.shared())
+            .map_ok(|(a, b)| Rc::new(a.add(b)) as _);
+
+        Self(PyFuture::new(sum))
+    }
+
+    #[pymethod]
+    fn ramp(&self, stops: PyListRef, vm: &VirtualMachine) -> PyResult<Self> {
+        let stops = stops
+            .borrow_elements()
+            .iter()
+            .map(|stop| {
+                let (position, color) = <(FloatLike, PyVec3)>::try_from_object(vm, stop.clone())?;
+                Ok((position.as_f32(), color.into_vec()))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let ramp = self.shared()
+            .map_ok(move |input| Rc::new(input.ramp(stops)) as _);
+
+        Ok(Self(PyFuture::new(ramp)))
+    }
+
+    #[pymethod]
+    fn scalar_map(&self, low: PyVec3, high: PyVec3) -> Self {
+        let (low, high) = (low.into_vec(), high.into_vec());
+        let mapped = self.shared()
+            .map_ok(move |input| {
+                let map = move |t: f32| {
+                    let t = t.max(0.).min(1.);
+                    (1. - t) * low + t * high
+                };
+                Rc::new(input.map_scalar(map)) as _
+            });
+
+        Self(PyFuture::new(mapped))
+    }
+
+    #[pymethod]
+    fn hsv(&self, hue: FloatLike, saturation: FloatLike, value: FloatLike) -> Self {
+        let adjusted = self.shared()
+            .map_ok(move |input| Rc::new(input.hsv(hue.as_f32(), saturation.as_f32(), value.as_f32())) as _);
+
+        Self(PyFuture::new(adjusted))
+    }
+
+    #[pymethod]
+    fn invert(&self) -> Self {
+        let inverted = self.shared()
+            .map_ok(|input| Rc::new(input.invert()) as _);
+
+        Self(PyFuture::new(inverted))
+    }
+
+    #[pymethod]
+    fn clamp(&self, min: PyVec3, max: PyVec3) -> Self {
+        let clamped = self.shared()
+            .map_ok(move |input| Rc::new(input.clamp(min.into_vec(), max.into_vec())) as _);
+
+        Self(PyFuture::new(clamped))
+    }
+
+    #[pymethod]
+    fn triplanar(&self, scale: FloatLike, sharpness: FloatLike, object_space: bool) -> Self {
+        let space = if object_space { TriplanarSpace::Object } else { TriplanarSpace::World };
+        let projected = self.shared()
+            .map_ok(move |texture| {
+                let triplanar = Triplanar::new(texture, scale.as_f32())
+                    .sharpness(sharpness.as_f32())
+                    .space(space);
+                Rc::new(triplanar) as _
+            });
+
+        Self(PyFuture::new(projected))
+    }
+}
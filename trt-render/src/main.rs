@@ -3,14 +3,15 @@ use indicatif::{ParallelProgressIterator, ProgressStyle, ProgressBar};
 use rayon::prelude::*;
 
 use std::sync::Arc;
-use std::{path::Path, time};
+use std::{fs, path::Path, time};
 
 use trt_core::prelude::*;
 
+use trt_core::atmosphere::Atmosphere;
 use trt_core::camera::CameraBuilder;
 use trt_core::hit::{Sphere, MovingSphere, RectBuilder, HitBox, BVHNode};
 use trt_core::material::Lambertian;
-use trt_core::texture::{Constant, Checker, Noise, Image};
+use trt_core::texture::{Constant, Checker, ColorSpace, Noise, Image};
 use trt_core::world;
 use trt_core::scene::Scene;
 
@@ -106,7 +107,7 @@ pub fn random_scene() -> impl Hit {
 pub fn two_perlin_spheres() -> impl Hit {
     let pertext = Noise::from_scale(5.);
 
-    let earth_img = load_image("./assets/earthmap.jpg");
+    let earth_img = load_image("./assets/earthmap.jpg", ColorSpace::Srgb);
 
     world![
         Sphere::builder()
@@ -128,7 +129,7 @@ pub fn two_perlin_spheres() -> impl Hit {
 pub fn simple_light() -> impl Hit {
     let pertext = || Noise::from_scale(4.);
 
-    let earth_img = load_image("./assets/earthmap.jpg");
+    let earth_img = load_image("./assets/earthmap.jpg", ColorSpace::Srgb);
 
     world![
         Sphere::builder()
@@ -156,7 +157,7 @@ pub fn cornell_box() -> impl Hit {
     let white = || Lambertian::colored((0.73, 0.73, 0.73));
     let green = Lambertian::colored((0.12, 0.45, 0.15));
 
-    let oreo_img = load_image("./assets/oreo.jpg");
+    let oreo_img = load_image("./assets/oreo.jpg", ColorSpace::Srgb);
 
     let img_text = Arc::new(Lambertian::new(oreo_img));
 
@@ -222,7 +223,7 @@ fn final_scene() -> impl Hit {
         }
     }
 
-    let globibot_img = load_image("./assets/globibot.png");
+    let globibot_img = load_image("./assets/globibot.png", ColorSpace::Srgb);
 
     let ns = 1000;
     for _ in 0..ns {
@@ -262,10 +263,6 @@ fn final_scene() -> impl Hit {
             .metallic_fuzzed((0.8, 0.8, 0.9), 10),
         sphere(),
         sphere().constant_medium(0.2, (0.2, 0.4, 0.9)),
-        Sphere::builder()
-            .radius(5_000)
-            .dielectric(1.5)
-            .constant_medium(0.0001, (1, 1, 1)),
         Sphere::builder()
             .center((400, 200, 400))
             .radius(100)
@@ -299,6 +296,7 @@ fn run() -> image::RgbImage {
         samples_per_px: SAMPLES_PER_PX,
         rays_per_sample: RAYS_PER_SAMPLE,
         ambiant_color: Vec3::splat(0),
+        atmosphere: Some(Atmosphere::new(0.0001, (1, 1, 1)).bounded((0, 0, 0), 5_000.)),
     };
 
     let progress = ProgressBar::new((WIDTH * HEIGHT) as u64)
@@ -324,13 +322,9 @@ fn run() -> image::RgbImage {
         .expect("Image and buffer dimension mismatch")
 }
 
-fn load_image(path: impl AsRef<Path>) -> Image {
-    let img = image::open(path)
-        .expect("Failed to load image")
-        .into_rgb();
-
-    let (width, height) = img.dimensions();
-    Image::load(img.into_vec(), width as _, height as _)
+fn load_image(path: impl AsRef<Path>, color_space: ColorSpace) -> Image {
+    let bytes = fs::read(path).expect("Failed to open image");
+    Image::decode(&bytes, color_space).expect("Failed to load image")
 }
 
 fn main() {